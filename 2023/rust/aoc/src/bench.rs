@@ -0,0 +1,117 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc_utils::{AocError, DaySolution, Part, PuzzleInput};
+
+use crate::format_time;
+
+/// Median timings of a day over `runs` runs
+pub struct Timings {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Duration,
+    pub parts: Vec<(Part, Duration)>,
+}
+
+fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    match samples.len() {
+        0 => Duration::ZERO,
+        len if len % 2 == 0 => (samples[len / 2 - 1] + samples[len / 2]) / 2,
+        len => samples[len / 2],
+    }
+}
+
+/// Solve the day up to `runs` times, stopping early once `budget` is spent,
+/// so that a slow day is timed once instead of holding the whole run
+pub fn bench_day(
+    solution: &dyn DaySolution,
+    input: &PuzzleInput,
+    runs: usize,
+    budget: Duration,
+) -> Result<Timings, AocError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut parts: Vec<Vec<Duration>> = vec![Vec::with_capacity(runs); Part::ALL.len()];
+    let mut spent = Duration::ZERO;
+    while parse.len() < runs.max(1) && (parse.is_empty() || spent < budget) {
+        let run = solution.solve(input, &Part::ALL)?;
+        spent += run.parse_time;
+        parse.push(run.parse_time);
+        for (samples, answer) in parts.iter_mut().zip(run.answers) {
+            spent += answer.time;
+            samples.push(answer.time);
+        }
+    }
+    Ok(Timings {
+        day: solution.day(),
+        runs: parse.len(),
+        parse: median(parse),
+        parts: Part::ALL
+            .into_iter()
+            .zip(parts.into_iter().map(median))
+            .collect(),
+    })
+}
+
+/// Markdown table with a row per day, times are medians
+pub fn markdown_table(timings: &[Timings]) -> String {
+    let mut table = String::from("| day | parse |");
+    for part in Part::ALL {
+        write!(table, " {part} |").unwrap();
+    }
+    table.push_str(" total | runs |\n|----:|------:|");
+    table.push_str(&"------:|".repeat(Part::ALL.len()));
+    table.push_str("------:|-----:|\n");
+    for timing in timings {
+        let total = timing.parse + timing.parts.iter().map(|(_, x)| *x).sum::<Duration>();
+        write!(
+            table,
+            "| {:02} | {} |",
+            timing.day,
+            format_time(timing.parse)
+        )
+        .unwrap();
+        for (_, time) in timing.parts.iter() {
+            write!(table, " {} |", format_time(*time)).unwrap();
+        }
+        writeln!(table, " {} | {} |", format_time(total), timing.runs).unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{markdown_table, median, Timings};
+    use aoc_utils::Part;
+    use rstest::rstest;
+    use std::time::Duration;
+
+    #[rstest]
+    #[case(&[], 0)]
+    #[case(&[3], 3)]
+    #[case(&[5, 1, 3], 3)]
+    #[case(&[4, 1, 2, 8], 3)]
+    fn test_median(#[case] samples: &[u64], #[case] expected: u64) {
+        let samples = samples.iter().copied().map(Duration::from_millis).collect();
+        assert_eq!(median(samples), Duration::from_millis(expected));
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let timings = [Timings {
+            day: 1,
+            runs: 10,
+            parse: Duration::from_micros(20),
+            parts: vec![
+                (Part::One, Duration::from_micros(100)),
+                (Part::Two, Duration::from_millis(2)),
+            ],
+        }];
+        assert_eq!(
+            markdown_table(&timings),
+            "| day | parse | part1 | part2 | total | runs |\n\
+             |----:|------:|------:|------:|------:|-----:|\n\
+             | 01 | 20.00µs | 100.00µs | 2.00ms | 2.12ms | 10 |\n"
+        );
+    }
+}
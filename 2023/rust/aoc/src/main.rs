@@ -0,0 +1,570 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+
+mod bench;
+mod memory;
+mod parallel;
+mod report;
+
+use aoc_utils::{
+    AocError, DaySolution, InputSource, KnownAnswers, Normalize, ParseMode, Part, Registry, Run,
+    Verdict,
+};
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+
+const YEAR: u16 = 2023;
+/// A day that took this long stops being repeated by `aoc bench`
+const BENCH_BUDGET: Duration = Duration::from_secs(5);
+
+#[global_allocator]
+static ALLOCATOR: memory::CountingAlloc = memory::CountingAlloc;
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log to stderr, `-v` for debug events and span timings, `-vv` for trace events.
+    /// Without it the filter comes from RUST_LOG
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every implemented day with `--all`
+    Run(RunArgs),
+    /// Solve the days with a known answer and report the ones that changed
+    Check(CheckArgs),
+    /// Time parse and both parts of every day on its committed input, as a markdown table
+    Bench(BenchArgs),
+    /// Report what the loader normalizes in the inputs: CRLF, trailing whitespace and blank lines
+    Lint(LintArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Run every implemented day and print a table of answers, timings and peak memory
+    #[arg(long)]
+    all: bool,
+    /// Only solve this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Input file, `-` reads stdin, defaults to the day's input.txt
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Year of the advent
+    #[arg(long, default_value_t = YEAR)]
+    year: u16,
+    /// Skip malformed input lines with a warning instead of failing
+    #[arg(long)]
+    lenient: bool,
+    /// Seconds each part may run before it is cancelled and reported as timed out.
+    /// The parts then run on their own threads, so `--all` can't show their peak memory
+    #[arg(long, value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Days solved at the same time by `--all`, 0 means one per CPU
+    #[arg(long, requires = "all", default_value_t = 1)]
+    jobs: usize,
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// `partN = answer` lines, or a table with `--all`
+    Text,
+    /// An array of answer records with timings and the input hash
+    Json,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Only check this day
+    #[arg(long)]
+    day: Option<u8>,
+    /// Known answers, defaults to answers.toml at the root of the workspace
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// Seconds each part may run before it is cancelled and reported as timed out
+    #[arg(long, value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Year of the advent
+    #[arg(long, default_value_t = YEAR)]
+    year: u16,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only bench this day
+    #[arg(long)]
+    day: Option<u8>,
+    /// Runs per day, the reported times are medians
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+    /// Write the table to this file instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// Year of the advent
+    #[arg(long, default_value_t = YEAR)]
+    year: u16,
+}
+
+#[derive(Args)]
+struct LintArgs {
+    /// Only lint this day's input
+    #[arg(long, conflicts_with = "input")]
+    day: Option<u8>,
+    /// Lint this file instead of the days' inputs
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Rewrite the inputs with the normalized text
+    #[arg(long)]
+    fix: bool,
+    /// Don't report or drop the blank lines at the end of the input
+    #[arg(long)]
+    keep_trailing_blank_lines: bool,
+    /// Year of the advent
+    #[arg(long, default_value_t = YEAR)]
+    year: u16,
+}
+
+impl RunArgs {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::One],
+            Some(2) => vec![Part::Two],
+            _ => Part::ALL.to_vec(),
+        }
+    }
+
+    fn parse_mode(&self) -> ParseMode {
+        if self.lenient {
+            ParseMode::Lenient
+        } else {
+            ParseMode::Strict
+        }
+    }
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry
+        .register::<day01::Day01>()
+        .register::<day02::Day02>()
+        .register::<day03::Day03>()
+        .register::<day04::Day04>()
+        .register::<day05::Day05>()
+        .register::<day06::Day06>()
+        .register::<day07::Day07>()
+        .register::<day09::Day09>()
+        .register::<day10::Day10>()
+        .register::<day11::Day11>();
+    registry
+}
+
+/// A solved day, with the hash of the input it was solved on
+struct Solved {
+    run: Run,
+    input_hash: String,
+}
+
+fn solve(
+    solution: &dyn DaySolution,
+    source: InputSource,
+    parts: &[Part],
+    mode: ParseMode,
+    timeout: Option<Duration>,
+) -> Result<Solved, AocError> {
+    let input = source.load()?.with_mode(mode);
+    let run = solution.solve_within(&input, parts, timeout);
+    input.report_warnings(solution.day());
+    Ok(Solved {
+        run: run?,
+        input_hash: report::input_hash(input.text()),
+    })
+}
+
+fn print_json(records: &[report::AnswerRecord]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(records).map_err(|err| err.to_string())?;
+    println!("{json}");
+    Ok(())
+}
+
+fn run_day(registry: &Registry, args: &RunArgs, day: u8) -> Result<(), String> {
+    let solution = registry
+        .get(args.year, day)
+        .ok_or_else(|| format!("day {day} of {} is not implemented", args.year))?;
+    let source = match &args.input {
+        Some(input) => InputSource::from_path(input),
+        None => solution.input().resolve(),
+    };
+    let solved = solve(
+        solution,
+        source,
+        &args.parts(),
+        args.parse_mode(),
+        args.timeout,
+    )
+    .map_err(|err| err.to_string())?;
+    if args.format == Format::Json {
+        print_json(&report::records(
+            args.year,
+            day,
+            &solved.run,
+            &solved.input_hash,
+        ))?;
+    }
+    let mut errors = Vec::new();
+    for answer in solved.run.answers {
+        match answer.value {
+            Ok(value) if args.format == Format::Text => println!("{} = {value}", answer.part),
+            Ok(_) => {}
+            Err(err) => errors.push(err.to_string()),
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join(", "))
+    }
+}
+
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+        .filter(|x| !x.is_zero())
+        .ok_or_else(|| format!("expected a positive number of seconds, found {value}"))
+}
+
+fn format_time(time: Duration) -> String {
+    format!("{time:.2?}")
+}
+
+/// What `aoc run --all` found for one day
+struct DayReport {
+    day: u8,
+    /// Load, parse and solve, as seen from outside the solution
+    wall_time: Duration,
+    /// None with `--timeout`, the parts then allocate on threads that aren't watched
+    peak_memory: Option<usize>,
+    result: Result<Solved, String>,
+}
+
+fn report_day(solution: &dyn DaySolution, args: &RunArgs) -> DayReport {
+    let start = Instant::now();
+    let (result, peak_memory) = memory::peak_usage(|| {
+        parallel::catch_panic(|| {
+            // AOC_INPUT and --input point to a single file, so they make no sense here
+            solve(
+                solution,
+                solution.input().default_source(),
+                &args.parts(),
+                args.parse_mode(),
+                args.timeout,
+            )
+        })
+    });
+    DayReport {
+        day: solution.day(),
+        wall_time: start.elapsed(),
+        peak_memory: args.timeout.is_none().then_some(peak_memory),
+        result: match result {
+            Ok(solved) => solved.map_err(|err| err.to_string()),
+            Err(message) => Err(format!("panicked: {message}")),
+        },
+    }
+}
+
+/// Runs every day of the year on `--jobs` threads, a failing or panicking day
+/// is reported in its row without stopping the others
+fn run_all(registry: &Registry, args: &RunArgs) -> Result<(), String> {
+    let solutions: Vec<&dyn DaySolution> =
+        registry.iter().filter(|x| x.year() == args.year).collect();
+    if solutions.is_empty() {
+        return Err(format!("no day of {} is implemented", args.year));
+    }
+    let jobs = match args.jobs {
+        0 => thread::available_parallelism().map_or(1, usize::from),
+        jobs => jobs,
+    };
+    let start = Instant::now();
+    let reports = parallel::map_jobs(&solutions, jobs, |solution| report_day(*solution, args));
+    let elapsed = start.elapsed();
+
+    let parts = args.parts();
+    let mut header = vec!["day".to_owned()];
+    header.extend(parts.iter().map(Part::to_string));
+    header.push("parse".to_owned());
+    header.extend(parts.iter().map(|part| format!("{part} time")));
+    header.extend(["wall".to_owned(), "peak memory".to_owned()]);
+
+    let mut rows = vec![header];
+    let mut records = Vec::new();
+    let mut total = Duration::ZERO;
+    let mut failed = 0;
+    for report in &reports {
+        let mut row = vec![format!("{:02}", report.day)];
+        match &report.result {
+            Ok(Solved { run, input_hash }) => {
+                records.extend(report::records(args.year, report.day, run, input_hash));
+                let mut day_failed = false;
+                for answer in &run.answers {
+                    match &answer.value {
+                        Ok(value) => row.push(value.clone()),
+                        Err(err) => {
+                            day_failed = true;
+                            if args.format == Format::Json {
+                                eprintln!("error: day {:02}: {err}", report.day);
+                            }
+                            row.push(format!("error: {err}"));
+                        }
+                    }
+                }
+                failed += usize::from(day_failed);
+                row.push(format_time(run.parse_time));
+                row.extend(run.answers.iter().map(|x| format_time(x.time)));
+            }
+            Err(err) => {
+                failed += 1;
+                if args.format == Format::Json {
+                    eprintln!("error: day {:02}: {err}", report.day);
+                }
+                row.push(format!("error: {err}"));
+                row.extend(vec![String::new(); 2 * parts.len()]);
+            }
+        }
+        total += report.wall_time;
+        row.push(format_time(report.wall_time));
+        row.push(
+            report
+                .peak_memory
+                .map_or("n/a".to_owned(), memory::format_bytes),
+        );
+        rows.push(row);
+    }
+
+    if args.format == Format::Json {
+        print_json(&records)?;
+    } else {
+        print_table(&rows);
+        println!(
+            "total: {}, {} elapsed with --jobs {jobs}",
+            format_time(total),
+            format_time(elapsed)
+        );
+    }
+
+    if failed > 0 {
+        Err(format!("days that failed: {failed}"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Print `rows` as a table with left aligned columns, the first row is the header
+fn print_table(rows: &[Vec<String>]) {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|col| {
+            rows.iter()
+                .filter_map(|row| row.get(col))
+                .map(|x| x.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    for row in rows.iter() {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        println!("{}", line.join(" | ").trim_end());
+    }
+}
+
+/// Compare the answers of every implemented day with the known ones, using the committed inputs
+fn check(registry: &Registry, args: &CheckArgs) -> Result<(), String> {
+    let path = args
+        .answers
+        .clone()
+        .unwrap_or_else(KnownAnswers::default_path);
+    let answers = KnownAnswers::load(path).map_err(|err| err.to_string())?;
+    let solutions: Vec<&dyn DaySolution> = registry
+        .iter()
+        .filter(|x| x.year() == args.year && args.day.is_none_or(|day| day == x.day()))
+        .collect();
+    if solutions.is_empty() {
+        return Err(match args.day {
+            Some(day) => format!("day {day} of {} is not implemented", args.year),
+            None => format!("no day of {} is implemented", args.year),
+        });
+    }
+
+    let mut failed = 0;
+    for solution in solutions {
+        let day = solution.day();
+        let parts = answers.parts(args.year, day);
+        if parts.is_empty() {
+            println!("{day:02}: no known answers");
+            continue;
+        }
+        let run = match solve(
+            solution,
+            solution.input().default_source(),
+            &parts,
+            ParseMode::Strict,
+            args.timeout,
+        ) {
+            Ok(solved) => solved.run,
+            Err(err) => {
+                failed += parts.len();
+                println!("{day:02}: error: {err}");
+                continue;
+            }
+        };
+        for answer in run.answers {
+            let value = match answer.value {
+                Ok(value) => value,
+                Err(err) => {
+                    failed += 1;
+                    println!("{day:02} {}: error: {err}", answer.part);
+                    continue;
+                }
+            };
+            match answers.verify(args.year, day, answer.part, &value) {
+                Verdict::Correct => {
+                    println!(
+                        "{day:02} {}: ok ({})",
+                        answer.part,
+                        format_time(answer.time)
+                    )
+                }
+                Verdict::Wrong { expected } => {
+                    failed += 1;
+                    println!(
+                        "{day:02} {}: MISMATCH, expected {expected}, got {value}",
+                        answer.part
+                    );
+                }
+                Verdict::Unknown => println!("{day:02} {}: no known answer", answer.part),
+            }
+        }
+    }
+
+    if failed > 0 {
+        Err(format!("answers that don't match: {failed}"))
+    } else {
+        Ok(())
+    }
+}
+
+fn run_bench(registry: &Registry, args: &BenchArgs) -> Result<(), String> {
+    if cfg!(debug_assertions) {
+        eprintln!("warning: benchmarking a debug build, use `cargo run --release`");
+    }
+    let mut timings = Vec::new();
+    for solution in registry
+        .iter()
+        .filter(|x| x.year() == args.year && args.day.is_none_or(|day| day == x.day()))
+    {
+        let input = solution
+            .input()
+            .default_source()
+            .load()
+            .map_err(|err| err.to_string())?;
+        eprintln!("bench day {:02}", solution.day());
+        let timing = bench::bench_day(solution, &input, args.runs as usize, BENCH_BUDGET)
+            .map_err(|err| err.to_string())?;
+        timings.push(timing);
+    }
+    if timings.is_empty() {
+        return Err(format!("no day of {} to bench", args.year));
+    }
+
+    let table = bench::markdown_table(&timings);
+    match &args.output {
+        Some(path) => std::fs::write(path, table)
+            .map_err(|err| format!("couldn't write {}: {err}", path.display())),
+        None => {
+            print!("{table}");
+            Ok(())
+        }
+    }
+}
+
+fn lint(registry: &Registry, args: &LintArgs) -> Result<(), String> {
+    let normalize = Normalize {
+        keep_trailing_blank_lines: args.keep_trailing_blank_lines,
+    };
+    let paths: Vec<PathBuf> = match &args.input {
+        Some(input) => vec![input.clone()],
+        None => registry
+            .iter()
+            .filter(|x| x.year() == args.year && args.day.is_none_or(|day| day == x.day()))
+            .map(|x| x.input().default_path())
+            .collect(),
+    };
+    if paths.is_empty() {
+        return Err(format!("no input of {} to lint", args.year));
+    }
+
+    let mut failed = 0;
+    for path in paths {
+        let (input, changes) = match aoc_utils::load_input_file_with(&path, normalize) {
+            Ok(loaded) => loaded,
+            Err(err) => {
+                failed += 1;
+                println!("{}: error: {err}", path.display());
+                continue;
+            }
+        };
+        if changes.is_empty() {
+            println!("{}: clean", path.display());
+        } else if args.fix {
+            std::fs::write(&path, input.text())
+                .map_err(|err| format!("couldn't write {}: {err}", path.display()))?;
+            println!("{}: fixed, {changes}", path.display());
+        } else {
+            failed += 1;
+            println!("{}: {changes}", path.display());
+        }
+    }
+
+    if failed > 0 {
+        Err(format!("inputs that need attention: {failed}"))
+    } else {
+        Ok(())
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    aoc_utils::init_tracing(cli.verbose);
+    let registry = registry();
+    let result = match cli.command {
+        Command::Run(args) => match args.day {
+            Some(day) => run_day(&registry, &args, day),
+            None => run_all(&registry, &args),
+        },
+        Command::Check(args) => check(&registry, &args),
+        Command::Bench(args) => run_bench(&registry, &args),
+        Command::Lint(args) => lint(&registry, &args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Call `f` on every item from `jobs` threads, the results keep the order of `items`.
+///
+/// Each call runs from start to end on a single thread, so per thread
+/// measurements like [`peak_usage`](crate::memory::peak_usage) stay meaningful.
+pub fn map_jobs<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, items.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let indx = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(indx) else {
+                            break done;
+                        };
+                        done.push((indx, f(item)));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("panics are caught by the callers"))
+            .collect()
+    });
+    results.sort_unstable_by_key(|(indx, _)| *indx);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Run `f`, turning a panic into an error holding its message
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(&*payload))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{catch_panic, map_jobs};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1)]
+    #[case(3)]
+    #[case(100)]
+    fn test_map_jobs_keeps_order(#[case] jobs: usize) {
+        let items: Vec<u64> = (0..20).collect();
+        assert_eq!(
+            map_jobs(&items, jobs, |x| x * x),
+            items.iter().map(|x| x * x).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 4), Ok(4));
+        let days = [1, 6, 2];
+        let results = map_jobs(&days, 2, |&day| {
+            catch_panic(|| {
+                if day == 6 {
+                    panic!("This race is unwinnable");
+                }
+                day
+            })
+        });
+        assert_eq!(
+            results,
+            [Ok(1), Err("This race is unwinnable".to_owned()), Ok(2)]
+        );
+    }
+}
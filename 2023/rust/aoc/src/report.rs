@@ -0,0 +1,95 @@
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use aoc_utils::{Part, Run};
+
+/// One answer as written by `aoc run --format json`, times are in nanoseconds
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct AnswerRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// None when the part failed, like on a timeout
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub parse_time_ns: u64,
+    pub solve_time_ns: u64,
+    /// `sha256:<hex>` of the input after normalization
+    pub input_hash: String,
+}
+
+pub fn input_hash(text: &str) -> String {
+    let digest = Sha256::digest(text.as_bytes());
+    let hex: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!("sha256:{hex}")
+}
+
+pub fn records(year: u16, day: u8, run: &Run, input_hash: &str) -> Vec<AnswerRecord> {
+    run.answers
+        .iter()
+        .map(|answer| AnswerRecord {
+            year,
+            day,
+            part: match answer.part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            answer: answer.value.as_ref().ok().cloned(),
+            error: answer.value.as_ref().err().map(ToString::to_string),
+            parse_time_ns: run.parse_time.as_nanos() as u64,
+            solve_time_ns: answer.time.as_nanos() as u64,
+            input_hash: input_hash.to_owned(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{input_hash, records};
+    use aoc_utils::{Answer, AocError, Part, Progress, Run};
+    use std::time::Duration;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(
+            input_hash("abc"),
+            "sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_records_json() {
+        let run = Run {
+            parse_time: Duration::from_nanos(1500),
+            answers: vec![
+                Answer {
+                    part: Part::One,
+                    value: Err(AocError::Timeout {
+                        day: 9,
+                        part: Part::One,
+                        timeout: Duration::from_millis(5),
+                        progress: Progress::default(),
+                    }),
+                    time: Duration::from_millis(5),
+                },
+                Answer {
+                    part: Part::Two,
+                    value: Ok("42".to_owned()),
+                    time: Duration::from_micros(3),
+                },
+            ],
+        };
+        let json = serde_json::to_string(&records(2023, 9, &run, "sha256:00")).unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"[{"year":2023,"day":9,"part":1,"answer":null,"#,
+                r#""error":"day 9, part1: timed out after 5ms, no progress reported","#,
+                r#""parse_time_ns":1500,"solve_time_ns":5000000,"input_hash":"sha256:00"},"#,
+                r#"{"year":2023,"day":9,"part":2,"answer":"42","parse_time_ns":1500,"#,
+                r#""solve_time_ns":3000,"input_hash":"sha256:00"}]"#
+            )
+        );
+    }
+}
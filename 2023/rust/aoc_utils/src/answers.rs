@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::{AocError, Part};
+
+const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+/// Answers are numbers most of the time, but a few puzzles want a text
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Number(i64),
+    Text(String),
+}
+
+impl From<Value> for String {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Number(number) => number.to_string(),
+            Value::Text(text) => text,
+        }
+    }
+}
+
+/// Answers of both parts of a day, either may be missing
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DayAnswers {
+    part1: Option<Value>,
+    part2: Option<Value>,
+}
+
+impl DayAnswers {
+    pub(crate) fn into_parts(self) -> [(Part, Option<String>); 2] {
+        [
+            (Part::One, self.part1.map(String::from)),
+            (Part::Two, self.part2.map(String::from)),
+        ]
+    }
+}
+
+/// Outcome of comparing an answer with the known one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+/// Answers already accepted by the site, read from a toml file with one table per year and day:
+///
+/// ```toml
+/// [2023.1]
+/// part1 = 53194
+/// part2 = 54249
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u16, u8, Part), String>,
+}
+
+impl KnownAnswers {
+    /// `answers.toml` at the root of the workspace
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(ANSWERS_FILE_NAME)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|err| AocError::open(path, err))?;
+        Self::parse(&text).map_err(|message| AocError::Answers {
+            path: path.to_owned(),
+            message,
+        })
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let years: BTreeMap<String, BTreeMap<String, DayAnswers>> =
+            toml::from_str(text).map_err(|err| err.message().to_owned())?;
+        let mut answers = BTreeMap::new();
+        for (year, days) in years {
+            let year: u16 = year.parse().map_err(|_| format!("invalid year {year}"))?;
+            for (day, parts) in days {
+                let day: u8 = day
+                    .parse()
+                    .ok()
+                    .filter(|day| (1..=25).contains(day))
+                    .ok_or_else(|| format!("invalid day {day} of {year}"))?;
+                for (part, value) in parts.into_parts() {
+                    if let Some(value) = value {
+                        answers.insert((year, day, part), value);
+                    }
+                }
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    /// Parts of the day that have a known answer
+    pub fn parts(&self, year: u16, day: u8) -> Vec<Part> {
+        Part::ALL
+            .into_iter()
+            .filter(|&part| self.get(year, day, part).is_some())
+            .collect()
+    }
+
+    pub fn verify(&self, year: u16, day: u8, part: Part, value: &str) -> Verdict {
+        match self.get(year, day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == value => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KnownAnswers, Verdict};
+    use crate::Part;
+    use rstest::rstest;
+
+    const ANSWERS: &str = r#"
+[2023.1]
+part1 = 142
+part2 = "281"
+
+[2023.5]
+part1 = 35
+"#;
+
+    #[rstest]
+    #[case(1, Part::One, "142", Verdict::Correct)]
+    #[case(1, Part::Two, "281", Verdict::Correct)]
+    #[case(5, Part::One, "36", Verdict::Wrong { expected: "35".to_owned() })]
+    #[case(5, Part::Two, "46", Verdict::Unknown)]
+    #[case(2, Part::One, "8", Verdict::Unknown)]
+    fn test_verify(
+        #[case] day: u8,
+        #[case] part: Part,
+        #[case] value: &str,
+        #[case] expected: Verdict,
+    ) {
+        let answers = KnownAnswers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.verify(2023, day, part, value), expected);
+    }
+
+    #[rstest]
+    #[case("[2023.26]\npart1 = 1")]
+    #[case("[twenty.1]\npart1 = 1")]
+    #[case("[2023.1]\npart3 = 1")]
+    #[case("[2023.1]\npart1 = 1.5")]
+    fn test_parse_invalid(#[case] text: &str) {
+        assert!(KnownAnswers::parse(text).is_err());
+    }
+
+    #[test]
+    fn test_workspace_answers() {
+        let answers = KnownAnswers::load(KnownAnswers::default_path()).unwrap();
+        assert_eq!(answers.parts(2023, 1), Part::ALL);
+    }
+}
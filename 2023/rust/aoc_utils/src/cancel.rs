@@ -0,0 +1,178 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::panic;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Cancellation of a running part, cheap to clone and to check.
+///
+/// The runner stops waiting for a part that runs out of time and cancels its token.
+/// The part keeps running on its own thread until it returns, so a long loop should
+/// check [`CancelToken::is_cancelled`] every few thousand steps and give up early.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<State>);
+
+#[derive(Debug, Default)]
+struct State {
+    cancelled: AtomicBool,
+    done: AtomicU64,
+    total: AtomicU64,
+}
+
+/// How far a part got, in steps of its own choosing
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    pub done: u64,
+    /// 0 when the part never said how many steps it has
+    pub total: u64,
+}
+
+impl Display for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.done == 0 && self.total == 0 {
+            f.write_str("no progress reported")
+        } else if self.total == 0 {
+            write!(f, "{} steps done", self.done)
+        } else {
+            let percent = self.done as f64 * 100.0 / self.total as f64;
+            write!(f, "{}/{} steps done ({percent:.1}%)", self.done, self.total)
+        }
+    }
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The token of the part running on this thread, one that is never
+    /// cancelled when the part is called outside of the runner, like in tests.
+    ///
+    /// Take it before handing work to other threads, they don't share it.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.borrow().clone().unwrap_or_default())
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn set_total(&self, total: u64) {
+        self.0.total.store(total, Ordering::Relaxed);
+    }
+
+    pub fn advance(&self, steps: u64) {
+        self.0.done.fetch_add(steps, Ordering::Relaxed);
+    }
+
+    pub fn progress(&self) -> Progress {
+        Progress {
+            done: self.0.done.load(Ordering::Relaxed),
+            total: self.0.total.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Call `f` with this token as the [current](CancelToken::current) one
+    pub fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        let value = f();
+        CURRENT.with(|current| current.replace(previous));
+        value
+    }
+
+    /// Like [`CancelToken::run`] on a worker thread, waiting at most `timeout` for it.
+    ///
+    /// Returns None if `f` didn't return in time: the token is cancelled and the
+    /// worker is left to finish on its own, whether `f` checks the token or not.
+    /// A panic in `f` is resumed on the calling thread.
+    pub fn run_with_timeout<T: Send + 'static>(
+        &self,
+        timeout: Duration,
+        f: impl FnOnce() -> T + Send + 'static,
+    ) -> Option<T> {
+        let (sender, receiver) = mpsc::channel();
+        let token = self.clone();
+        let worker = thread::spawn(move || {
+            // the runner may have stopped listening, nothing to do about it
+            let _ = sender.send(token.run(f));
+        });
+        match receiver.recv_timeout(timeout) {
+            Ok(value) => Some(value),
+            Err(RecvTimeoutError::Timeout) => {
+                self.cancel();
+                None
+            }
+            Err(RecvTimeoutError::Disconnected) => match worker.join() {
+                Err(payload) => panic::resume_unwind(payload),
+                Ok(()) => unreachable!("the worker always sends before returning"),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CancelToken, Progress};
+    use rstest::rstest;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_timeout_cancels() {
+        let token = CancelToken::new();
+        let value = token.run_with_timeout(Duration::from_millis(20), || {
+            let current = CancelToken::current();
+            while !current.is_cancelled() {
+                current.advance(1);
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        });
+        assert_eq!(value, None);
+        assert!(token.is_cancelled());
+        assert!(token.progress().done > 0);
+        assert!(!CancelToken::current().is_cancelled());
+    }
+
+    #[test]
+    fn test_timeout_without_cooperation() {
+        let token = CancelToken::new();
+        let start = Instant::now();
+        let value = token.run_with_timeout(Duration::from_millis(20), || {
+            std::thread::sleep(Duration::from_secs(60));
+        });
+        assert_eq!(value, None);
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_fast_part_is_not_cancelled() {
+        let token = CancelToken::new();
+        let value = token.run_with_timeout(Duration::from_secs(60), || 4);
+        assert_eq!(value, Some(4));
+        assert!(!token.is_cancelled());
+    }
+
+    #[test]
+    #[should_panic(expected = "boom")]
+    fn test_timeout_resumes_panics() {
+        CancelToken::new().run_with_timeout(Duration::from_secs(60), || panic!("boom"));
+    }
+
+    #[rstest]
+    #[case(Progress { done: 0, total: 0 }, "no progress reported")]
+    #[case(Progress { done: 12, total: 0 }, "12 steps done")]
+    #[case(Progress { done: 1, total: 8 }, "1/8 steps done (12.5%)")]
+    fn test_progress_display(#[case] progress: Progress, #[case] expected: &str) {
+        assert_eq!(progress.to_string(), expected);
+    }
+}
@@ -0,0 +1,54 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::{Part, Progress};
+
+#[derive(thiserror::Error, Debug)]
+pub enum AocError {
+    #[error("input file {} not found", path.display())]
+    MissingFile { path: PathBuf },
+    #[error("couldn't open {}: {source}", path.display())]
+    Open { path: PathBuf, source: io::Error },
+    #[error("couldn't read stdin: {source}")]
+    Stdin { source: io::Error },
+    #[error("line {line} of {} is not valid UTF-8", path.display())]
+    InvalidUtf8 { path: PathBuf, line: usize },
+    #[error("day {day}, line {line}: {message}")]
+    Parse {
+        day: u8,
+        line: usize,
+        message: String,
+    },
+    #[error("day {day}, {part}: timed out after {timeout:?}, {progress}")]
+    Timeout {
+        day: u8,
+        part: Part,
+        timeout: Duration,
+        progress: Progress,
+    },
+    #[error("invalid answers file {}: {message}", path.display())]
+    Answers { path: PathBuf, message: String },
+}
+
+impl AocError {
+    pub fn parse(day: u8, line: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            day,
+            line,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn open(path: &Path, source: io::Error) -> Self {
+        match source.kind() {
+            io::ErrorKind::NotFound => Self::MissingFile {
+                path: path.to_owned(),
+            },
+            _ => Self::Open {
+                path: path.to_owned(),
+                source,
+            },
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::io::Write;
+use std::path::Path;
+
+use crate::answers::DayAnswers;
+use crate::{AocError, Part, PuzzleInput, Solution};
+
+/// An example from the puzzle text: `examples/<name>.txt` in the day crate,
+/// with the answers given by the text in `examples/<name>.toml`
+///
+/// ```toml
+/// part1 = 142
+/// ```
+pub struct Example {
+    pub input: PuzzleInput,
+    answers: [Option<String>; 2],
+}
+
+impl Example {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let answers_path = path.with_extension("toml");
+        let text = std::fs::read_to_string(&answers_path)
+            .map_err(|err| AocError::open(&answers_path, err))?;
+        let answers: DayAnswers = toml::from_str(&text).map_err(|err| AocError::Answers {
+            path: answers_path.clone(),
+            message: err.message().to_owned(),
+        })?;
+        Ok(Self {
+            input: crate::load_input_file(path)?,
+            answers: answers.into_parts().map(|(_, value)| value),
+        })
+    }
+
+    /// Answer given by the puzzle text, examples often cover a single part
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        self.answers[part as usize].as_deref()
+    }
+}
+
+/// Body of the tests generated by [`example_tests!`](crate::example_tests):
+/// solve `part` of the example at `path` and compare it with the answer in the `.toml`.
+///
+/// A part the `.toml` has no answer for is skipped with a note on stderr, printed even when
+/// the test output is captured. Panics if the `.toml` gives no answer at all, so a fixture
+/// never passes without checking anything.
+pub fn assert_example<S: Solution>(path: &Path, part: Part) {
+    let example = Example::load(path).unwrap_or_else(|err| panic!("{err}"));
+    assert!(
+        Part::ALL
+            .iter()
+            .any(|&part| example.expected(part).is_some()),
+        "{} has no answer for either part",
+        path.with_extension("toml").display()
+    );
+    let Some(expected) = example.expected(part) else {
+        // straight to stderr, the test harness only captures print! and eprint!
+        let _ = writeln!(
+            std::io::stderr(),
+            "skipped {part} of {}: no answer in the .toml",
+            path.display()
+        );
+        return;
+    };
+    let input = S::parse(&example.input).unwrap_or_else(|err| panic!("{err}"));
+    let found = match part {
+        Part::One => S::part1(&input).to_string(),
+        Part::Two => S::part2(&input).to_string(),
+    };
+    assert_eq!(found, expected, "{part} of {}", path.display());
+}
+
+/// Generate an rstest case per `examples/*.txt` fixture and part, checking it against
+/// the answer in the `.toml` next to the fixture.
+///
+/// The fixtures are listed at compile time, touch the crate after adding one.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ident) => {
+        #[cfg(test)]
+        mod example_tests {
+            use std::path::PathBuf;
+
+            use $crate::Part;
+
+            #[rstest::rstest]
+            fn example(
+                #[files("examples/*.txt")] path: PathBuf,
+                #[values(Part::One, Part::Two)] part: Part,
+            ) {
+                $crate::assert_example::<super::$solution>(&path, part);
+            }
+        }
+    };
+}
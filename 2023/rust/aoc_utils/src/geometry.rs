@@ -0,0 +1,321 @@
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Sub};
+
+/// Anything that moves a point by a fixed `(dx, dy)`, `y` grows going south
+pub trait Offset {
+    fn offset(&self) -> (isize, isize);
+}
+
+impl Offset for (isize, isize) {
+    fn offset(&self) -> (isize, isize) {
+        *self
+    }
+}
+
+/// Position in a grid, `x` is the column and `y` the row
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// Position on an unbounded plane, it can go below zero
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IPoint {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Point) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Move by `offset`, `None` if the point would go below zero or overflow
+    pub fn checked_move(&self, offset: impl Offset) -> Option<Point> {
+        let (dx, dy) = offset.offset();
+        Some(Point {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// Move by `offset`, `None` if the point would leave a `width`x`height` grid
+    pub fn checked_move_within(
+        &self,
+        offset: impl Offset,
+        width: usize,
+        height: usize,
+    ) -> Option<Point> {
+        self.checked_move(offset)
+            .filter(|next| next.x < width && next.y < height)
+    }
+
+    pub fn to_signed(&self) -> Option<IPoint> {
+        Some(IPoint {
+            x: self.x.try_into().ok()?,
+            y: self.y.try_into().ok()?,
+        })
+    }
+}
+
+impl IPoint {
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: &IPoint) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &IPoint) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// `None` if any coordinate is negative
+    pub fn to_unsigned(&self) -> Option<Point> {
+        Some(Point {
+            x: self.x.try_into().ok()?,
+            y: self.y.try_into().ok()?,
+        })
+    }
+}
+
+impl<O: Offset> Add<O> for Point {
+    type Output = Option<Self>;
+
+    fn add(self, rhs: O) -> Self::Output {
+        self.checked_move(rhs)
+    }
+}
+
+impl<O: Offset> AddAssign<O> for Point {
+    fn add_assign(&mut self, rhs: O) {
+        *self = self.checked_move(rhs).unwrap();
+    }
+}
+
+impl<O: Offset> Add<O> for IPoint {
+    type Output = Self;
+
+    fn add(self, rhs: O) -> Self::Output {
+        let (dx, dy) = rhs.offset();
+        IPoint {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
+    }
+}
+
+impl<O: Offset> AddAssign<O> for IPoint {
+    fn add_assign(&mut self, rhs: O) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for IPoint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        IPoint {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl From<Point> for IPoint {
+    /// Panics if a coordinate doesn't fit in an `isize`, see [`Point::to_signed`]
+    fn from(value: Point) -> Self {
+        value.to_signed().unwrap()
+    }
+}
+
+/// The four cardinal directions, in clockwise order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 2) % 4]
+    }
+}
+
+impl Offset for Direction {
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+/// The cardinal and diagonal directions, in clockwise order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// Turn 45 degrees counterclockwise
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 7) % 8]
+    }
+
+    /// Turn 45 degrees clockwise
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 8]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 4) % 8]
+    }
+}
+
+impl Offset for Direction8 {
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::North => Direction8::North,
+            Direction::East => Direction8::East,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+impl Display for Direction8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Direction8, IPoint, Offset, Point};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Direction::North, Direction::West, Direction::East, Direction::South)]
+    #[case(Direction::East, Direction::North, Direction::South, Direction::West)]
+    #[case(Direction::West, Direction::South, Direction::North, Direction::East)]
+    fn test_turns(
+        #[case] dir: Direction,
+        #[case] left: Direction,
+        #[case] right: Direction,
+        #[case] opposite: Direction,
+    ) {
+        assert_eq!(dir.turn_left(), left);
+        assert_eq!(dir.turn_right(), right);
+        assert_eq!(dir.opposite(), opposite);
+    }
+
+    #[test]
+    fn test_turns8() {
+        for dir in Direction8::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(
+                dir.turn_right().turn_right().opposite(),
+                dir.turn_left().turn_left()
+            );
+            let (dx, dy) = dir.offset();
+            assert_eq!(dir.opposite().offset(), (-dx, -dy));
+        }
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+    }
+
+    #[rstest]
+    #[case(Point::new(1, 1), Direction::North, Some(Point::new(1, 0)))]
+    #[case(Point::new(1, 0), Direction::North, None)]
+    #[case(Point::new(0, 1), Direction::West, None)]
+    #[case(Point::new(2, 1), Direction::East, None)]
+    #[case(Point::new(1, 1), Direction::South, None)]
+    #[case(Point::new(1, 0), Direction::South, Some(Point::new(1, 1)))]
+    fn test_checked_move_within(
+        #[case] point: Point,
+        #[case] dir: Direction,
+        #[case] expected: Option<Point>,
+    ) {
+        assert_eq!(point.checked_move_within(dir, 3, 2), expected);
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point::new(1, 6), Point::new(5, 11));
+        assert_eq!(a.manhattan(&b), 9);
+        assert_eq!(a.chebyshev(&b), 5);
+        let (a, b) = (IPoint::new(-2, 3), IPoint::new(1, -1));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a + Direction8::SouthWest, IPoint::new(-3, 4));
+        assert_eq!(IPoint::new(0, -1).to_unsigned(), None);
+    }
+}
@@ -0,0 +1,436 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Direction, Direction8, Offset, Point};
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("row {row} is {found} cells wide, expected {expected}")]
+pub struct RaggedGridError {
+    /// 0-based index of the first row with a different width
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+/// Rectangular matrix of cells stored row by row
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Build the grid from rows of text, converting every char with `cell`
+    pub fn parse<S: AsRef<str>>(
+        lines: impl IntoIterator<Item = S>,
+        mut cell: impl FnMut(Point, char) -> T,
+    ) -> Result<Self, RaggedGridError> {
+        let mut lines = lines.into_iter().peekable();
+        let width = lines.peek().map_or(0, |x| x.as_ref().chars().count());
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for (y, line) in lines.enumerate() {
+            let before = cells.len();
+            cells.extend(
+                line.as_ref()
+                    .chars()
+                    .enumerate()
+                    .map(|(x, c)| cell(Point { x, y }, c)),
+            );
+            if cells.len() - before != width {
+                return Err(RaggedGridError {
+                    row: y,
+                    expected: width,
+                    found: cells.len() - before,
+                });
+            }
+            height += 1;
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, RaggedGridError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(RaggedGridError {
+                    row: y,
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).flat_map(|x| self.column(x))
+    }
+
+    /// Every point of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(self.cells.iter_mut())
+    }
+
+    /// Points of the cells matching `predicate`, row by row
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Move `point` by `offset`, `None` if it leaves the grid
+    pub fn step(&self, point: Point, offset: impl Offset) -> Option<Point> {
+        point.checked_move_within(offset, self.width, self.height)
+    }
+
+    /// North, east, south and west neighbours of `point` that are inside the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(point, dir))
+    }
+
+    /// Neighbours of `point` inside the grid, diagonals included
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(point, dir))
+    }
+
+    /// Render every cell with `render`, one row per line
+    pub fn display_with<'a, D: Display>(
+        &'a self,
+        render: impl Fn(&T) -> D + 'a,
+    ) -> impl Display + 'a {
+        GridDisplay { grid: self, render }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Swap rows and columns
+    pub fn transpose(&self) -> Self {
+        Self {
+            cells: self.columns().flatten().cloned().collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Self {
+            cells: (0..self.width)
+                .flat_map(|x| {
+                    (0..self.height)
+                        .rev()
+                        .map(move |y| self.cells[y * self.width + x].clone())
+                })
+                .collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self {
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|x| (0..self.height).map(move |y| self.cells[y * self.width + x].clone()))
+                .collect(),
+            width: self.height,
+            height: self.width,
+        }
+    }
+}
+
+/// Borrowed view of a rectangular block of text, one byte per cell
+///
+/// Rows must be separated by a single `\n`, the last one may end with it too.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ByteGrid<'a> {
+    bytes: &'a [u8],
+    width: usize,
+    height: usize,
+}
+
+impl<'a> ByteGrid<'a> {
+    pub fn new(text: &'a str) -> Result<Self, RaggedGridError> {
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        if text.is_empty() {
+            return Ok(Self {
+                bytes: &[],
+                width: 0,
+                height: 0,
+            });
+        }
+        let width = text.find('\n').unwrap_or(text.len());
+        let mut height = 0;
+        for (y, row) in text.split('\n').enumerate() {
+            if row.len() != width {
+                return Err(RaggedGridError {
+                    row: y,
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            height += 1;
+        }
+        Ok(Self {
+            bytes: text.as_bytes(),
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: &Point) -> Option<u8> {
+        self.contains(point)
+            .then(|| self.bytes[point.y * (self.width + 1) + point.x])
+    }
+
+    pub fn row(&self, y: usize) -> Option<&'a [u8]> {
+        let start = y * (self.width + 1);
+        (y < self.height).then(|| &self.bytes[start..start + self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        (0..self.height).flat_map(|y| self.row(y))
+    }
+
+    /// Copy the view in a [`Grid`], converting every byte with `cell`
+    pub fn map<T>(&self, mut cell: impl FnMut(Point, u8) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(self.width * self.height);
+        for (y, row) in self.rows().enumerate() {
+            cells.extend(
+                row.iter()
+                    .enumerate()
+                    .map(|(x, &byte)| cell(Point { x, y }, byte)),
+            );
+        }
+        Grid {
+            cells,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn to_grid(&self) -> Grid<u8> {
+        self.map(|_, byte| byte)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(&point)
+            .unwrap_or_else(|| panic!("{point:?} out of a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(&point)
+            .unwrap_or_else(|| panic!("{point:?} out of a {width}x{height} grid"))
+    }
+}
+
+struct GridDisplay<'a, T, F> {
+    grid: &'a Grid<T>,
+    render: F,
+}
+
+impl<T, D: Display, F: Fn(&T) -> D> Display for GridDisplay<'_, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid.rows() {
+            for cell in row {
+                write!(f, "{}", (self.render)(cell))?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        GridDisplay {
+            grid: self,
+            render: |cell: &T| cell.to_string(),
+        }
+        .fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ByteGrid, Grid, RaggedGridError};
+    use crate::geometry::Point;
+    use rstest::rstest;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(&["abc", "def"], |_, c| c).unwrap()
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        assert_eq!(
+            Grid::parse(&["abc", "de"], |_, c| c),
+            Err(RaggedGridError {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), Some('a'))]
+    #[case(Point::new(2, 1), Some('f'))]
+    #[case(Point::new(3, 0), None)]
+    #[case(Point::new(0, 2), None)]
+    fn test_get(#[case] point: Point, #[case] expected: Option<char>) {
+        assert_eq!(grid().get(&point).copied(), expected);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        let rows: Vec<String> = grid.rows().map(|x| x.iter().collect()).collect();
+        assert_eq!(rows, ["abc", "def"]);
+        let columns: Vec<String> = grid.columns().map(|x| x.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), &[(1, 0), (0, 1)])]
+    #[case(Point::new(1, 1), &[(1, 0), (2, 1), (0, 1)])]
+    fn test_neighbours4(#[case] point: Point, #[case] expected: &[(usize, usize)]) {
+        let found: Vec<_> = grid().neighbours4(point).map(|p| (p.x, p.y)).collect();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        assert_eq!(grid().neighbours8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid().neighbours8(Point::new(0, 1)).count(), 3);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn test_display_with() {
+        let grid = grid();
+        let upper = grid.display_with(|c| c.to_ascii_uppercase());
+        assert_eq!(upper.to_string(), "ABC\nDEF\n");
+    }
+
+    #[test]
+    fn test_byte_grid() {
+        let view = ByteGrid::new("abc\ndef\n").unwrap();
+        assert_eq!((view.width(), view.height()), (3, 2));
+        assert_eq!(view.get(&Point::new(1, 1)), Some(b'e'));
+        assert_eq!(view.get(&Point::new(3, 0)), None);
+        assert_eq!(view.rows().collect::<Vec<_>>(), [b"abc", b"def"]);
+        assert_eq!(view.map(|_, byte| byte as char), grid());
+        assert_eq!(
+            ByteGrid::new("abc\nde\n"),
+            Err(RaggedGridError {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(ByteGrid::new("").unwrap().rows().count(), 0);
+    }
+}
@@ -0,0 +1,156 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use crate::{AocError, InputSource, PuzzleInput};
+
+/// Environment variable pointing to the input file to use instead of the day's `input.txt`,
+/// `-` reads stdin
+pub const INPUT_ENV_VAR: &str = "AOC_INPUT";
+/// Command line argument pointing to the input file, wins over [`INPUT_ENV_VAR`]
+pub const INPUT_ARG: &str = "--input";
+const INPUT_FILE_NAME: &str = "input.txt";
+
+/// Where a day crate keeps its puzzle input, build it with [`day_input!`](crate::day_input)
+/// so that it points to the calling crate and not to the current directory.
+#[derive(Clone, Copy, Debug)]
+pub struct DayInput {
+    pub manifest_dir: &'static str,
+    /// The day's `input.txt`, built into the binary by the `embed` feature
+    pub embedded: Option<&'static str>,
+}
+
+impl DayInput {
+    /// `input.txt` next to the day's `Cargo.toml`
+    pub fn default_path(&self) -> PathBuf {
+        Path::new(self.manifest_dir).join(INPUT_FILE_NAME)
+    }
+
+    /// The embedded input if there is one, the default path otherwise
+    pub fn default_source(&self) -> InputSource {
+        match self.embedded {
+            Some(text) => InputSource::Static(text),
+            None => InputSource::File(self.default_path()),
+        }
+    }
+
+    /// Resolve the input source, every source overrides the previous one:
+    /// 1. the [default source](DayInput::default_source)
+    /// 2. the [`INPUT_ENV_VAR`] environment variable
+    /// 3. the `--input <path>` command line argument
+    ///
+    /// A path of `-` means stdin.
+    pub fn resolve(&self) -> InputSource {
+        self.resolve_from(std::env::var_os(INPUT_ENV_VAR), std::env::args_os().skip(1))
+    }
+
+    pub fn load(&self) -> Result<PuzzleInput, AocError> {
+        self.resolve().load()
+    }
+
+    fn resolve_from(
+        &self,
+        env_var: Option<OsString>,
+        args: impl IntoIterator<Item = OsString>,
+    ) -> InputSource {
+        input_arg(args)
+            .or_else(|| env_var.filter(|x| !x.is_empty()).map(PathBuf::from))
+            .map_or_else(|| self.default_source(), InputSource::from_path)
+    }
+}
+
+/// Accepts both `--input <path>` and `--input=<path>`
+fn input_arg(args: impl IntoIterator<Item = OsString>) -> Option<PathBuf> {
+    let mut args = args.into_iter();
+    let mut found = None;
+    while let Some(arg) = args.next() {
+        let Some(arg) = arg.to_str() else {
+            continue;
+        };
+        if arg == INPUT_ARG {
+            found = args.next().map(PathBuf::from);
+        } else if let Some(path) = arg
+            .strip_prefix(INPUT_ARG)
+            .and_then(|x| x.strip_prefix('='))
+        {
+            found = Some(PathBuf::from(path));
+        }
+    }
+    found
+}
+
+/// Build the [`DayInput`] of the crate calling this macro
+#[cfg(not(feature = "embed"))]
+#[macro_export]
+macro_rules! day_input {
+    () => {
+        $crate::DayInput {
+            manifest_dir: env!("CARGO_MANIFEST_DIR"),
+            embedded: None,
+        }
+    };
+}
+
+/// Build the [`DayInput`] of the crate calling this macro, with its `input.txt` built in
+#[cfg(feature = "embed")]
+#[macro_export]
+macro_rules! day_input {
+    () => {
+        $crate::DayInput {
+            manifest_dir: env!("CARGO_MANIFEST_DIR"),
+            embedded: Some(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/input.txt"
+            ))),
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DayInput;
+    use crate::InputSource;
+    use rstest::rstest;
+    use std::ffi::OsString;
+    use std::path::PathBuf;
+
+    const DAY: DayInput = DayInput {
+        manifest_dir: "/aoc/day01",
+        embedded: None,
+    };
+
+    #[rstest]
+    #[case(None, &[], "/aoc/day01/input.txt")]
+    #[case(Some(""), &[], "/aoc/day01/input.txt")]
+    #[case(Some("env.txt"), &[], "env.txt")]
+    #[case(Some("env.txt"), &["--input", "arg.txt"], "arg.txt")]
+    #[case(None, &["--input=arg.txt"], "arg.txt")]
+    #[case(None, &["--input"], "/aoc/day01/input.txt")]
+    #[case(None, &["--inputs", "arg.txt"], "/aoc/day01/input.txt")]
+    #[case(None, &["--input", "-"], "-")]
+    #[case(Some("-"), &[], "-")]
+    fn test_resolve(#[case] env_var: Option<&str>, #[case] args: &[&str], #[case] expected: &str) {
+        assert_eq!(
+            DAY.resolve_from(env_var.map(OsString::from), args.iter().map(OsString::from)),
+            InputSource::from_path(PathBuf::from(expected))
+        );
+    }
+
+    #[rstest]
+    #[case(None, &[], InputSource::Static("1 2 3"))]
+    #[case(Some("env.txt"), &[], InputSource::File(PathBuf::from("env.txt")))]
+    #[case(None, &["--input", "-"], InputSource::Stdin)]
+    fn test_resolve_embedded(
+        #[case] env_var: Option<&str>,
+        #[case] args: &[&str],
+        #[case] expected: InputSource,
+    ) {
+        let day = DayInput {
+            embedded: Some("1 2 3"),
+            ..DAY
+        };
+        assert_eq!(
+            day.resolve_from(env_var.map(OsString::from), args.iter().map(OsString::from)),
+            expected
+        );
+    }
+}
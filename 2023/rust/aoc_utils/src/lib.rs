@@ -1,8 +1,43 @@
-use std::fs::OpenOptions;
-use std::io::{BufRead, BufReader};
+use std::path::Path;
 
-pub fn load_input_file(file_name: &str) -> impl Iterator<Item = String> {
-    let input_file = OpenOptions::new().read(true).open(file_name).unwrap();
-    let input_content = BufReader::new(input_file);
-    input_content.lines().flatten()
+mod answers;
+mod cancel;
+mod error;
+mod example;
+mod geometry;
+mod grid;
+mod input;
+mod normalize;
+mod parse;
+mod solution;
+mod source;
+mod trace;
+
+pub use answers::{KnownAnswers, Verdict};
+pub use cancel::{CancelToken, Progress};
+pub use error::AocError;
+pub use example::{assert_example, Example};
+pub use geometry::{Direction, Direction8, IPoint, Offset, Point};
+pub use grid::{ByteGrid, Grid, RaggedGridError};
+pub use input::{DayInput, INPUT_ARG, INPUT_ENV_VAR};
+pub use normalize::{Changes, Normalize};
+pub use parse::{ParseMode, ParseWarning, PuzzleInput, LENIENT_ARG};
+pub use solution::{run, run_with, Answer, DaySolution, Part, Registry, Run, Solution};
+pub use source::{InputSource, STDIN_PATH};
+pub use trace::{init_tracing, verbosity_from_args, VERBOSE_ARG};
+
+/// Read the whole input file at once, the lines are then borrowed from it.
+///
+/// The text goes through the default [`Normalize`], so CRLF line endings and
+/// trailing whitespace never reach the solution.
+pub fn load_input_file(file_name: impl AsRef<Path>) -> Result<PuzzleInput, AocError> {
+    InputSource::File(file_name.as_ref().to_owned()).load()
+}
+
+/// Like [`load_input_file`], also returning what `normalize` changed in the file
+pub fn load_input_file_with(
+    file_name: impl AsRef<Path>,
+    normalize: Normalize,
+) -> Result<(PuzzleInput, Changes), AocError> {
+    InputSource::File(file_name.as_ref().to_owned()).load_with(normalize)
 }
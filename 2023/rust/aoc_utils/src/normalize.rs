@@ -0,0 +1,163 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+
+/// How many line numbers [`Changes`] lists before cutting the list short
+const SHOWN_LINES: usize = 5;
+
+/// Clean-up applied to every input before it is split in lines: CRLF becomes LF,
+/// trailing whitespace is stripped and the last line always ends with a newline
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Normalize {
+    /// Keep the blank lines at the end of the input, they are dropped by default
+    pub keep_trailing_blank_lines: bool,
+}
+
+/// What [`Normalize::apply`] changed, line numbers are 1-based
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Changes {
+    pub crlf_lines: Vec<usize>,
+    pub trailing_whitespace_lines: Vec<usize>,
+    pub dropped_blank_lines: usize,
+    pub added_final_newline: bool,
+}
+
+impl Changes {
+    pub fn is_empty(&self) -> bool {
+        self == &Changes::default()
+    }
+}
+
+fn write_lines(f: &mut std::fmt::Formatter<'_>, lines: &[usize]) -> std::fmt::Result {
+    let shown: Vec<String> = lines
+        .iter()
+        .take(SHOWN_LINES)
+        .map(usize::to_string)
+        .collect();
+    write!(f, "{} lines ({}", lines.len(), shown.join(", "))?;
+    if lines.len() > SHOWN_LINES {
+        f.write_str(", ...")?;
+    }
+    f.write_str(")")
+}
+
+impl Display for Changes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return f.write_str("nothing to change");
+        }
+        let mut sep = "";
+        if !self.crlf_lines.is_empty() {
+            f.write_str("CRLF converted to LF on ")?;
+            write_lines(f, &self.crlf_lines)?;
+            sep = "; ";
+        }
+        if !self.trailing_whitespace_lines.is_empty() {
+            write!(f, "{sep}trailing whitespace stripped on ")?;
+            write_lines(f, &self.trailing_whitespace_lines)?;
+            sep = "; ";
+        }
+        if self.dropped_blank_lines > 0 {
+            write!(
+                f,
+                "{sep}{} trailing blank lines dropped",
+                self.dropped_blank_lines
+            )?;
+            sep = "; ";
+        }
+        if self.added_final_newline {
+            write!(f, "{sep}final newline added")?;
+        }
+        Ok(())
+    }
+}
+
+impl Normalize {
+    /// Returns `text` untouched when it is already clean
+    pub fn apply<'a>(&self, text: &'a str) -> (Cow<'a, str>, Changes) {
+        let mut changes = Changes::default();
+        let mut lines: Vec<&str> = Vec::new();
+        for (indx, raw) in text.split_inclusive('\n').enumerate() {
+            let line = raw.strip_suffix('\n').unwrap_or(raw);
+            if line.ends_with('\r') && raw.ends_with('\n') {
+                changes.crlf_lines.push(indx + 1);
+            }
+            let content = line.strip_suffix('\r').unwrap_or(line);
+            let trimmed = content.trim_end();
+            if trimmed.len() != content.len() {
+                changes.trailing_whitespace_lines.push(indx + 1);
+            }
+            lines.push(trimmed);
+        }
+        if !self.keep_trailing_blank_lines {
+            while lines.last().is_some_and(|x| x.is_empty()) {
+                lines.pop();
+                changes.dropped_blank_lines += 1;
+            }
+        }
+        // a blank line that is only whitespace was counted above, don't report it twice
+        let kept = lines.len();
+        changes
+            .trailing_whitespace_lines
+            .retain(|&line| line <= kept);
+        changes.crlf_lines.retain(|&line| line <= kept);
+        changes.added_final_newline = !lines.is_empty() && !text.ends_with('\n');
+
+        if changes.is_empty() {
+            return (Cow::Borrowed(text), changes);
+        }
+        let mut normalized = String::with_capacity(text.len());
+        for line in lines {
+            normalized.push_str(line);
+            normalized.push('\n');
+        }
+        (Cow::Owned(normalized), changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Changes, Normalize};
+    use rstest::rstest;
+    use std::borrow::Cow;
+
+    #[rstest]
+    #[case("", "", Changes::default())]
+    #[case("ab\ncd\n", "ab\ncd\n", Changes::default())]
+    #[case("ab\r\ncd\r\n", "ab\ncd\n", Changes { crlf_lines: vec![1, 2], ..Default::default() })]
+    #[case("ab \ncd\t\r\n", "ab\ncd\n", Changes { crlf_lines: vec![2], trailing_whitespace_lines: vec![1, 2], ..Default::default() })]
+    #[case("ab\ncd", "ab\ncd\n", Changes { added_final_newline: true, ..Default::default() })]
+    #[case("ab\n\n  \n", "ab\n", Changes { dropped_blank_lines: 2, ..Default::default() })]
+    #[case("ab\n\ncd\n", "ab\n\ncd\n", Changes::default())]
+    #[case("\n\n", "", Changes { dropped_blank_lines: 2, ..Default::default() })]
+    fn test_apply(#[case] text: &str, #[case] expected: &str, #[case] changes: Changes) {
+        let (normalized, found) = Normalize::default().apply(text);
+        assert_eq!(normalized, expected);
+        assert_eq!(found, changes);
+        assert_eq!(matches!(normalized, Cow::Borrowed(_)), found.is_empty());
+    }
+
+    #[test]
+    fn test_keep_trailing_blank_lines() {
+        let normalize = Normalize {
+            keep_trailing_blank_lines: true,
+        };
+        let (normalized, changes) = normalize.apply("ab\r\n\r\n");
+        assert_eq!(normalized, "ab\n\n");
+        assert_eq!(changes.crlf_lines, [1, 2]);
+    }
+
+    #[test]
+    fn test_display() {
+        let changes = Changes {
+            crlf_lines: (1..=7).collect(),
+            dropped_blank_lines: 1,
+            added_final_newline: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            changes.to_string(),
+            "CRLF converted to LF on 7 lines (1, 2, 3, 4, 5, ...); \
+             1 trailing blank lines dropped; final newline added"
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::ops::Range;
+
+use crate::{AocError, ByteGrid, RaggedGridError};
+
+/// Command line flag switching the day binaries to [`ParseMode::Lenient`]
+pub const LENIENT_ARG: &str = "--lenient";
+
+/// What to do with a line that can't be parsed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Fail with the line number and the parser message
+    #[default]
+    Strict,
+    /// Skip the line and keep a [`ParseWarning`] about it
+    Lenient,
+}
+
+impl ParseMode {
+    /// [`ParseMode::Lenient`] if the process was started with `--lenient`
+    pub fn from_args() -> Self {
+        if std::env::args().skip(1).any(|arg| arg == LENIENT_ARG) {
+            Self::Lenient
+        } else {
+            Self::Strict
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWarning {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// The puzzle input, read at once and handed out as borrowed lines,
+/// together with the policy used to parse them
+#[derive(Debug, Default)]
+pub struct PuzzleInput {
+    text: String,
+    /// Byte range of every line in `text`, line break excluded
+    lines: Vec<Range<usize>>,
+    mode: ParseMode,
+    warnings: RefCell<Vec<ParseWarning>>,
+}
+
+impl PuzzleInput {
+    /// Split `text` in lines, both `\n` and `\r\n` end a line.
+    /// `\r\n` becomes `\n` first, so [`PuzzleInput::byte_grid`] sees the same lines.
+    pub fn new(text: impl Into<String>) -> Self {
+        let mut text = text.into();
+        if text.contains("\r\n") {
+            text = text.replace("\r\n", "\n");
+        }
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for line in text.split_inclusive('\n') {
+            let content = line.strip_suffix('\n').unwrap_or(line);
+            let content = content.strip_suffix('\r').unwrap_or(content);
+            lines.push(start..start + content.len());
+            start += line.len();
+        }
+        Self {
+            text,
+            lines,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// The whole input, line breaks included as `\n`
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> impl ExactSizeIterator<Item = &str> + DoubleEndedIterator + Clone + '_ {
+        self.lines.iter().map(|range| &self.text[range.clone()])
+    }
+
+    /// 0-based, unlike the line numbers in errors and warnings
+    pub fn line(&self, indx: usize) -> Option<&str> {
+        self.lines.get(indx).map(|range| &self.text[range.clone()])
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// View the input as a rectangle of bytes, without copying it
+    pub fn byte_grid(&self) -> Result<ByteGrid<'_>, RaggedGridError> {
+        ByteGrid::new(&self.text)
+    }
+
+    pub fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Apply the parse policy to the outcome of parsing `line` (1-based):
+    /// strict turns the error into an [`AocError::Parse`],
+    /// lenient records a warning and returns `Ok(None)`
+    pub fn check<T, E: Display>(
+        &self,
+        day: u8,
+        line: usize,
+        parsed: Result<T, E>,
+    ) -> Result<Option<T>, AocError> {
+        match parsed {
+            Ok(parsed) => Ok(Some(parsed)),
+            Err(err) => {
+                let message = err.to_string().trim_end().to_owned();
+                match self.mode {
+                    ParseMode::Strict => Err(AocError::parse(day, line, message)),
+                    ParseMode::Lenient => {
+                        self.warnings
+                            .borrow_mut()
+                            .push(ParseWarning { line, message });
+                        Ok(None)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parse every line with `parse`, following the parse policy for the lines that fail
+    pub fn parse_lines<'a, T, E: Display>(
+        &'a self,
+        day: u8,
+        mut parse: impl FnMut(&'a str) -> Result<T, E>,
+    ) -> Result<Vec<T>, AocError> {
+        let mut parsed = Vec::with_capacity(self.lines.len());
+        for (indx, line) in self.lines().enumerate() {
+            parsed.extend(self.check(day, indx + 1, parse(line))?);
+        }
+        Ok(parsed)
+    }
+
+    /// Lines skipped so far in lenient mode
+    pub fn warnings(&self) -> Vec<ParseWarning> {
+        self.warnings.borrow().clone()
+    }
+
+    /// Print a summary of the skipped lines on stderr, if any
+    pub fn report_warnings(&self, day: u8) {
+        let warnings = self.warnings.borrow();
+        if warnings.is_empty() {
+            return;
+        }
+        eprintln!("day {day}: skipped {} lines", warnings.len());
+        for warning in warnings.iter() {
+            eprintln!("  {warning}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseMode, ParseWarning, PuzzleInput};
+    use crate::AocError;
+    use rstest::rstest;
+
+    fn input(mode: ParseMode) -> PuzzleInput {
+        PuzzleInput::new("1\ntwo\n3\n").with_mode(mode)
+    }
+
+    #[rstest]
+    #[case("", &[])]
+    #[case("a", &["a"])]
+    #[case("a\n", &["a"])]
+    #[case("a\r\nb\r\n", &["a", "b"])]
+    #[case("a\n\nb", &["a", "", "b"])]
+    #[case("a\n\n", &["a", ""])]
+    fn test_lines(#[case] text: &str, #[case] expected: &[&str]) {
+        let input = PuzzleInput::new(text);
+        assert_eq!(input.lines().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            input.lines().collect::<Vec<_>>(),
+            text.lines().collect::<Vec<_>>()
+        );
+        assert_eq!(input.len(), expected.len());
+        assert_eq!(input.line(1), expected.get(1).copied());
+    }
+
+    #[rstest]
+    #[case("ab\ncd\n")]
+    #[case("ab\r\ncd\r\n")]
+    #[case("ab\r\ncd")]
+    fn test_byte_grid_line_endings(#[case] text: &str) {
+        let input = PuzzleInput::new(text);
+        let grid = input.byte_grid().unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.row(1), Some(&b"cd"[..]));
+        assert_eq!(input.text(), text.replace("\r\n", "\n"));
+    }
+
+    #[test]
+    fn test_strict() {
+        let input = input(ParseMode::Strict);
+        let parsed = input.parse_lines(1, str::parse::<u32>);
+        assert!(matches!(
+            parsed,
+            Err(AocError::Parse {
+                day: 1,
+                line: 2,
+                ..
+            })
+        ));
+        assert!(input.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_lenient() {
+        let input = input(ParseMode::Lenient);
+        let parsed = input.parse_lines(1, str::parse::<u32>).unwrap();
+        assert_eq!(parsed, [1, 3]);
+        assert_eq!(
+            input.warnings(),
+            [ParseWarning {
+                line: 2,
+                message: "invalid digit found in string".to_owned()
+            }]
+        );
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::{
+    init_tracing, verbosity_from_args, AocError, CancelToken, DayInput, InputSource, ParseMode,
+    PuzzleInput,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("part1"),
+            Part::Two => f.write_str("part2"),
+        }
+    }
+}
+
+/// A day of the advent, parsed once and then solved for both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// Where the day keeps its input, usually `aoc_utils::day_input!()`
+    const INPUT: DayInput;
+
+    /// Shared with the worker thread running a part under a timeout
+    type Input: Send + Sync + 'static;
+
+    /// Build the input, malformed lines go through [`PuzzleInput::check`] or
+    /// [`PuzzleInput::parse_lines`] so they follow the chosen [`ParseMode`]
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, AocError>;
+    fn part1(input: &Self::Input) -> impl Display;
+    fn part2(input: &Self::Input) -> impl Display;
+}
+
+#[derive(Debug)]
+pub struct Answer {
+    pub part: Part,
+    /// The answer, or why this part has none while the others may
+    pub value: Result<String, AocError>,
+    /// Time spent solving this part, parsing excluded
+    pub time: Duration,
+}
+
+#[derive(Debug)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Object safe view of a [`Solution`], so that different days can live in the same [`Registry`]
+pub trait DaySolution: Send + Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn input(&self) -> DayInput;
+
+    /// Like [`DaySolution::solve`], a part still running after `timeout` gets its
+    /// [`CancelToken`] cancelled and [`AocError::Timeout`] as answer, the other parts keep theirs
+    fn solve_within(
+        &self,
+        input: &PuzzleInput,
+        parts: &[Part],
+        timeout: Option<Duration>,
+    ) -> Result<Run, AocError>;
+
+    fn solve(&self, input: &PuzzleInput, parts: &[Part]) -> Result<Run, AocError> {
+        self.solve_within(input, parts, None)
+    }
+}
+
+struct Entry<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> DaySolution for Entry<S> {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input(&self) -> DayInput {
+        S::INPUT
+    }
+
+    fn solve_within(
+        &self,
+        input: &PuzzleInput,
+        parts: &[Part],
+        timeout: Option<Duration>,
+    ) -> Result<Run, AocError> {
+        let start = Instant::now();
+        let input = tracing::info_span!("parse", day = S::DAY).in_scope(|| S::parse(input))?;
+        let input = Arc::new(input);
+        let parse_time = start.elapsed();
+        let answers = parts
+            .iter()
+            .map(|&part| {
+                let span = match part {
+                    Part::One => tracing::info_span!("part1", day = S::DAY),
+                    Part::Two => tracing::info_span!("part2", day = S::DAY),
+                };
+                let token = CancelToken::new();
+                let start = Instant::now();
+                let input = Arc::clone(&input);
+                let solve = move || {
+                    let _span = span.entered();
+                    match part {
+                        Part::One => S::part1(&input).to_string(),
+                        Part::Two => S::part2(&input).to_string(),
+                    }
+                };
+                let value =
+                    match timeout {
+                        Some(timeout) => token.run_with_timeout(timeout, solve).ok_or_else(|| {
+                            AocError::Timeout {
+                                day: S::DAY,
+                                part,
+                                timeout,
+                                progress: token.progress(),
+                            }
+                        }),
+                        None => Ok(token.run(solve)),
+                    };
+                Answer {
+                    part,
+                    value,
+                    time: start.elapsed(),
+                }
+            })
+            .collect();
+        Ok(Run {
+            parse_time,
+            answers,
+        })
+    }
+}
+
+/// Every known [`Solution`], indexed by (year, day)
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), Box<dyn DaySolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `S` to the registry, replacing any solution already registered for the same day
+    pub fn register<S: Solution + 'static>(&mut self) -> &mut Self {
+        self.solutions
+            .insert((S::YEAR, S::DAY), Box::new(Entry::<S>(PhantomData)));
+        self
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn DaySolution> {
+        self.solutions.get(&(year, day)).map(Box::as_ref)
+    }
+
+    /// Solutions sorted by year and day
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DaySolution> {
+        self.solutions.values().map(Box::as_ref)
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+/// Entry point of the day binaries: load the day's input and print both answers
+///
+/// The input comes from [`DayInput::resolve`], parsing is strict unless the binary
+/// is started with `--lenient`. Logging goes to stderr, see [`init_tracing`].
+pub fn run<S: Solution>() -> Result<(), AocError> {
+    init_tracing(verbosity_from_args());
+    run_with::<S>(S::INPUT.resolve())
+}
+
+/// Like [`run`], reading the input from `source`
+pub fn run_with<S: Solution>(source: InputSource) -> Result<(), AocError> {
+    let input = source.load()?.with_mode(ParseMode::from_args());
+    let run = Entry::<S>(PhantomData).solve(&input, &Part::ALL);
+    input.report_warnings(S::DAY);
+    for answer in run?.answers {
+        println!("{} = {}", answer.part, answer.value?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DaySolution, Entry, Part, Registry, Solution};
+    use crate::{AocError, DayInput, ParseMode, PuzzleInput};
+    use rstest::rstest;
+    use std::fmt::Display;
+    use std::marker::PhantomData;
+    use std::time::{Duration, Instant};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+        const INPUT: DayInput = DayInput {
+            manifest_dir: env!("CARGO_MANIFEST_DIR"),
+            embedded: None,
+        };
+
+        type Input = Vec<i64>;
+
+        fn parse(input: &PuzzleInput) -> Result<Self::Input, AocError> {
+            input.parse_lines(Self::DAY, str::parse)
+        }
+
+        fn part1(input: &Self::Input) -> impl Display {
+            input.iter().sum::<i64>()
+        }
+
+        fn part2(input: &Self::Input) -> impl Display {
+            input.iter().product::<i64>()
+        }
+    }
+
+    struct Other;
+
+    impl Solution for Other {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 2;
+        const INPUT: DayInput = DayInput {
+            manifest_dir: env!("CARGO_MANIFEST_DIR"),
+            embedded: None,
+        };
+
+        type Input = ();
+
+        fn parse(_: &PuzzleInput) -> Result<Self::Input, AocError> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Input) -> impl Display {
+            "one"
+        }
+
+        fn part2(_: &Self::Input) -> impl Display {
+            "two"
+        }
+    }
+
+    /// Part 2 takes far longer than any test timeout and never checks its token
+    struct Spin;
+
+    impl Solution for Spin {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 3;
+        const INPUT: DayInput = DayInput {
+            manifest_dir: env!("CARGO_MANIFEST_DIR"),
+            embedded: None,
+        };
+
+        type Input = ();
+
+        fn parse(_: &PuzzleInput) -> Result<Self::Input, AocError> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Input) -> impl Display {
+            "done"
+        }
+
+        fn part2(_: &Self::Input) -> impl Display {
+            std::thread::sleep(Duration::from_secs(60));
+            "too late"
+        }
+    }
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry.register::<Other>().register::<Sum>();
+        registry
+    }
+
+    #[rstest]
+    #[case(&[Part::One], &["9"])]
+    #[case(&[Part::Two], &["24"])]
+    #[case(&Part::ALL, &["9", "24"])]
+    fn test_solve(#[case] parts: &[Part], #[case] expected: &[&str]) {
+        let input = PuzzleInput::new("2\n3\n4\n");
+        let run = registry()
+            .get(2015, 1)
+            .unwrap()
+            .solve(&input, parts)
+            .unwrap();
+        let answers: Vec<_> = run
+            .answers
+            .iter()
+            .map(|x| (x.part, x.value.as_deref().unwrap()))
+            .collect();
+        let expected: Vec<_> = parts
+            .iter()
+            .copied()
+            .zip(expected.iter().copied())
+            .collect();
+        assert_eq!(answers, expected);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let input = PuzzleInput::new("");
+        let timeout = Some(Duration::from_millis(20));
+        let solution = Entry::<Spin>(PhantomData);
+        let run = solution
+            .solve_within(&input, &[Part::One], timeout)
+            .unwrap();
+        assert_eq!(run.answers[0].value.as_deref().unwrap(), "done");
+
+        let start = Instant::now();
+        let run = solution.solve_within(&input, &Part::ALL, timeout).unwrap();
+        assert!(start.elapsed() < Duration::from_secs(10));
+        // the part that finished keeps its answer
+        assert_eq!(run.answers[0].value.as_deref().unwrap(), "done");
+        let Err(AocError::Timeout { day, part, .. }) = &run.answers[1].value else {
+            panic!("expected a timeout, got {:?}", run.answers[1]);
+        };
+        assert_eq!((*day, *part), (3, Part::Two));
+    }
+
+    #[test]
+    fn test_registry_sorted() {
+        let registry = registry();
+        let days: Vec<_> = registry.iter().map(|x| (x.year(), x.day())).collect();
+        assert_eq!(days, [(2015, 1), (2015, 2)]);
+        assert!(registry.get(2015, 3).is_none());
+    }
+
+    fn bad_input(mode: ParseMode) -> PuzzleInput {
+        PuzzleInput::new("1\nx\n4\n").with_mode(mode)
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = bad_input(ParseMode::Strict);
+        let err = registry().get(2015, 1).unwrap().solve(&input, &Part::ALL);
+        assert!(matches!(
+            err,
+            Err(AocError::Parse {
+                day: 1,
+                line: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let input = bad_input(ParseMode::Lenient);
+        let run = registry()
+            .get(2015, 1)
+            .unwrap()
+            .solve(&input, &Part::ALL)
+            .unwrap();
+        assert_eq!(run.answers[0].value.as_deref().unwrap(), "5");
+        assert_eq!(input.warnings().len(), 1);
+        assert_eq!(input.warnings()[0].line, 2);
+    }
+}
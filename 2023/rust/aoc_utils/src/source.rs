@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::{AocError, Changes, Normalize, PuzzleInput};
+
+/// Input path meaning "read from stdin"
+pub const STDIN_PATH: &str = "-";
+
+/// Where the text of a puzzle input comes from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    /// Text built into the binary, usually with `include_str!`
+    Static(&'static str),
+    Owned(String),
+}
+
+impl InputSource {
+    /// [`STDIN_PATH`] reads stdin, anything else is a file
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if path.as_os_str() == STDIN_PATH {
+            Self::Stdin
+        } else {
+            Self::File(path)
+        }
+    }
+
+    /// Read the whole input through the default [`Normalize`]
+    pub fn load(self) -> Result<PuzzleInput, AocError> {
+        self.load_with(Normalize::default()).map(|(input, _)| input)
+    }
+
+    /// Like [`InputSource::load`], also returning what `normalize` changed in the text.
+    ///
+    /// Fails if the text isn't valid UTF-8 instead of replacing the bad bytes,
+    /// so a corrupted input never reaches the solution.
+    pub fn load_with(self, normalize: Normalize) -> Result<(PuzzleInput, Changes), AocError> {
+        let _span = tracing::info_span!("load", source = %self).entered();
+        let text = match self {
+            Self::File(path) => {
+                let bytes = std::fs::read(&path).map_err(|err| AocError::open(&path, err))?;
+                decode(bytes, &path)?
+            }
+            Self::Stdin => {
+                let mut bytes = Vec::new();
+                std::io::stdin()
+                    .lock()
+                    .read_to_end(&mut bytes)
+                    .map_err(|source| AocError::Stdin { source })?;
+                decode(bytes, Path::new(STDIN_PATH))?
+            }
+            Self::Static(text) => {
+                let (normalized, changes) = normalize.apply(text);
+                return Ok((PuzzleInput::new(normalized.into_owned()), changes));
+            }
+            Self::Owned(text) => text,
+        };
+        let (normalized, changes) = normalize.apply(&text);
+        let text = match normalized {
+            Cow::Borrowed(_) => text,
+            Cow::Owned(normalized) => normalized,
+        };
+        Ok((PuzzleInput::new(text), changes))
+    }
+}
+
+fn decode(bytes: Vec<u8>, path: &Path) -> Result<String, AocError> {
+    String::from_utf8(bytes).map_err(|err| {
+        let valid = &err.as_bytes()[..err.utf8_error().valid_up_to()];
+        AocError::InvalidUtf8 {
+            path: path.to_owned(),
+            line: valid.iter().filter(|&&byte| byte == b'\n').count() + 1,
+        }
+    })
+}
+
+impl From<PathBuf> for InputSource {
+    fn from(value: PathBuf) -> Self {
+        Self::from_path(value)
+    }
+}
+
+impl From<&'static str> for InputSource {
+    fn from(value: &'static str) -> Self {
+        Self::Static(value)
+    }
+}
+
+impl From<String> for InputSource {
+    fn from(value: String) -> Self {
+        Self::Owned(value)
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => f.write_str("stdin"),
+            Self::Static(_) => f.write_str("embedded input"),
+            Self::Owned(_) => f.write_str("in-memory input"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputSource;
+    use crate::AocError;
+    use rstest::rstest;
+    use std::path::PathBuf;
+
+    #[rstest]
+    #[case("-", InputSource::Stdin)]
+    #[case("input.txt", InputSource::File(PathBuf::from("input.txt")))]
+    #[case("./-", InputSource::File(PathBuf::from("./-")))]
+    fn test_from_path(#[case] path: &str, #[case] expected: InputSource) {
+        assert_eq!(InputSource::from_path(path), expected);
+    }
+
+    #[rstest]
+    #[case(InputSource::Static("ab\r\ncd"))]
+    #[case(InputSource::Owned("ab  \ncd\n\n".to_owned()))]
+    fn test_load_in_memory(#[case] source: InputSource) {
+        let input = source.load().unwrap();
+        assert_eq!(input.text(), "ab\ncd\n");
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let source = InputSource::from_path("/nonexistent/input.txt");
+        assert!(matches!(source.load(), Err(AocError::MissingFile { .. })));
+    }
+}
@@ -0,0 +1,66 @@
+use std::ffi::OsString;
+
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+/// Each `-v` raises the log level by one step, `-vv` counts twice
+pub const VERBOSE_ARG: &str = "-v";
+
+/// Count the `-v` flags the process was started with
+pub fn verbosity_from_args() -> u8 {
+    verbosity(std::env::args_os().skip(1))
+}
+
+fn verbosity(args: impl IntoIterator<Item = OsString>) -> u8 {
+    args.into_iter()
+        .filter_map(|arg| {
+            let flags = arg.to_str()?.strip_prefix(VERBOSE_ARG)?;
+            flags.bytes().all(|x| x == b'v').then_some(
+                u8::try_from(flags.len())
+                    .unwrap_or(u8::MAX)
+                    .saturating_add(1),
+            )
+        })
+        .fold(0, u8::saturating_add)
+}
+
+/// Send tracing events to stderr, so stdout only ever holds the answers.
+///
+/// Without `-v` the filter comes from `RUST_LOG` and defaults to warnings,
+/// `-v` enables the debug events and the timing of the load, parse and part spans,
+/// `-vv` the trace events too. Does nothing if a subscriber is already set.
+pub fn init_tracing(verbosity: u8) {
+    let filter = match verbosity {
+        0 => EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+        1 => EnvFilter::new("debug"),
+        _ => EnvFilter::new("trace"),
+    };
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verbosity;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(&[], 0)]
+    #[case(&["--lenient"], 0)]
+    #[case(&["-v"], 1)]
+    #[case(&["-vv"], 2)]
+    #[case(&["-v", "--input", "x.txt", "-v"], 2)]
+    #[case(&["-vx"], 0)]
+    fn test_verbosity(#[case] args: &[&str], #[case] expected: u8) {
+        assert_eq!(verbosity(args.iter().map(Into::into)), expected);
+    }
+
+    #[test]
+    fn test_verbosity_saturates() {
+        let flag = format!("-{}", "v".repeat(300));
+        assert_eq!(verbosity([flag.into()]), u8::MAX);
+    }
+}
@@ -0,0 +1,120 @@
+// https://adventofcode.com/2023/day/1
+use std::fmt::Display;
+
+use aoc_utils::{AocError, DayInput, PuzzleInput, Solution};
+
+struct Mapping<'a>(&'a str, char);
+
+static MAPPINGS: [Mapping; 9] = [
+    Mapping("one", '1'),
+    Mapping("two", '2'),
+    Mapping("three", '3'),
+    Mapping("four", '4'),
+    Mapping("five", '5'),
+    Mapping("six", '6'),
+    Mapping("seven", '7'),
+    Mapping("eight", '8'),
+    Mapping("nine", '9'),
+];
+
+fn parse_calibration(line: &str) -> String {
+    let line_legth = line.len();
+    (0..line_legth)
+        .flat_map(|start| {
+            let curr_line = &line[start..line_legth];
+            let first_char = curr_line.chars().next()?;
+            for mapping in &MAPPINGS {
+                if curr_line.starts_with(mapping.0) || first_char == mapping.1 {
+                    return Some(mapping.1);
+                }
+            }
+            None
+        })
+        .collect::<String>()
+}
+
+fn read_calibration(line: &str) -> Option<u32> {
+    let mut found_calibrations = line.chars().flat_map(|c| c.to_digit(10));
+    let first = found_calibrations.next()?;
+    let second = found_calibrations.last().unwrap_or(first);
+    Some(first * 10 + second)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    const INPUT: DayInput = aoc_utils::day_input!();
+
+    type Input = Vec<String>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, AocError> {
+        input.parse_lines(Self::DAY, |line| {
+            if parse_calibration(line).is_empty() {
+                Err(format!("no digit, spelled out or not, in {line:?}"))
+            } else {
+                Ok(line.to_owned())
+            }
+        })
+    }
+
+    fn part1(input: &Self::Input) -> impl Display {
+        // lines with only spelled out digits, like in the part 2 example, have no value yet
+        input
+            .iter()
+            .flat_map(|line| read_calibration(line))
+            .sum::<u32>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Display {
+        input
+            .iter()
+            .map(|line| parse_calibration(line))
+            .flat_map(|line| read_calibration(&line))
+            .sum::<u32>()
+    }
+}
+
+aoc_utils::example_tests!(Day01);
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use aoc_utils::{AocError, ParseMode, PuzzleInput, Solution};
+
+    use crate::{parse_calibration, read_calibration, Day01};
+
+    #[rstest]
+    #[case("46threevqs8114", Some(44))]
+    #[case("6lsjdagkjsdfog", Some(66))]
+    #[case("dshjgdfjgksdjf", None)]
+    fn test_read_calibration(#[case] input: &str, #[case] expected: Option<u32>) {
+        assert_eq!(read_calibration(input), expected);
+    }
+
+    #[rstest]
+    #[case("threevqs8114", "38114")]
+    #[case("eightwone", "821")]
+    #[case("6lsjdagkjsdfog", "6")]
+    #[case("dshjgdfjgksdjf", "")]
+    fn test_parse_calibration(#[case] input: &str, #[case] expected: String) {
+        assert_eq!(parse_calibration(input), expected);
+    }
+
+    #[test]
+    fn test_line_without_digits() {
+        let text = "1abc2\nnothing here\neightwothree\n";
+        let err = Day01::parse(&PuzzleInput::new(text)).err();
+        assert!(
+            matches!(err, Some(AocError::Parse { line: 2, .. })),
+            "{err:?}"
+        );
+        let input = PuzzleInput::new(text).with_mode(ParseMode::Lenient);
+        let lines = Day01::parse(&input).unwrap();
+        assert_eq!(lines, ["1abc2", "eightwothree"]);
+        assert_eq!(input.warnings().len(), 1);
+        assert_eq!(input.warnings()[0].line, 2);
+    }
+}
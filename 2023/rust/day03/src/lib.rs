@@ -0,0 +1,152 @@
+use std::{fmt::Display, ops::RangeInclusive, str::from_utf8};
+
+use aoc_utils::{AocError, DayInput, Grid, Point, PuzzleInput, Solution};
+
+fn get_number_index(line: &[u8], index: usize) -> Option<(RangeInclusive<usize>, u32)> {
+    if let Some(c) = line.get(index) {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+    } else {
+        return None;
+    }
+    let mut start = index;
+    let mut end = index;
+    let string_size = line.len();
+
+    if index > usize::MIN {
+        start -= 1;
+    }
+    if index < string_size {
+        end += 1;
+    }
+
+    while let Some(c) = line.get(start) {
+        if c.is_ascii_digit() {
+            if start == usize::MIN {
+                break;
+            }
+            start -= 1;
+        } else {
+            start += 1;
+            break;
+        }
+    }
+    while let Some(c) = line.get(end) {
+        if c.is_ascii_digit() {
+            if end == string_size {
+                break;
+            }
+            end += 1;
+        } else {
+            break;
+        }
+    }
+    end -= 1;
+    let num = from_utf8(&line[start..=end]).ok()?.parse().ok()?;
+
+    Some((start..=end, num))
+}
+
+// #,$,%,&,*,+,-,/,=
+fn is_symbol(c: &char) -> bool {
+    matches!(c, '#' | '$' | '%' | '&' | '*' | '+' | '-' | '/' | '=' | '@')
+}
+fn is_gear(c: &char) -> bool {
+    matches!(c, '*')
+}
+
+pub struct Schematic(Grid<u8>);
+
+impl Schematic {
+    /// Returns the part numbers found and the gear ratios found
+    fn scan(&self) -> (Vec<u32>, Vec<u32>) {
+        let grid = &self.0;
+        let mut nums_found: Vec<(usize, RangeInclusive<usize>, u32)> = Vec::new();
+        let mut gears_found: Vec<u32> = Vec::new();
+        let mut partial_gear: Vec<(RangeInclusive<usize>, u32)> = Vec::new();
+        let mut partial_found: Vec<(usize, RangeInclusive<usize>, u32)> = Vec::new();
+
+        for (working_line, line) in grid.rows().enumerate() {
+            partial_found.clear();
+            for (x, c) in line.iter().enumerate() {
+                if is_symbol(&(*c as char)) {
+                    partial_gear.clear();
+                    'direction: for Point { x, y } in grid.neighbours8(Point::new(x, working_line))
+                    {
+                        for found in partial_found.iter() {
+                            if found.0 == y && found.1.contains(&x) {
+                                continue 'direction;
+                            }
+                        }
+                        let Some(row) = grid.row(y) else {
+                            continue;
+                        };
+                        if let Some((range, num)) = get_number_index(row, x) {
+                            partial_found.push((y, range.clone(), num));
+                            if is_gear(&(*c as char)) {
+                                partial_gear.push((range, num));
+                            }
+                        }
+                    }
+                    if partial_gear.len() == 2 {
+                        gears_found.push(partial_gear[0].1 * partial_gear[1].1);
+                    }
+                }
+            }
+            nums_found.append(&mut partial_found);
+        }
+        (nums_found.into_iter().map(|x| x.2).collect(), gears_found)
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    const INPUT: DayInput = aoc_utils::day_input!();
+
+    type Input = Schematic;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, AocError> {
+        if input.is_empty() {
+            return Err(AocError::parse(Self::DAY, 1, "the schematic is empty"));
+        }
+        input
+            .byte_grid()
+            .map(|view| Schematic(view.to_grid()))
+            .map_err(|err| AocError::parse(Self::DAY, err.row + 1, err.to_string()))
+    }
+
+    fn part1(schematic: &Self::Input) -> impl Display {
+        let (nums_found, _) = schematic.scan();
+        nums_found.iter().sum::<u32>()
+    }
+
+    fn part2(schematic: &Self::Input) -> impl Display {
+        let (_, gears_found) = schematic.scan();
+        gears_found.iter().sum::<u32>()
+    }
+}
+
+aoc_utils::example_tests!(Day03);
+
+#[cfg(test)]
+mod test {
+    use super::get_number_index;
+    use rstest::rstest;
+    use std::ops::RangeInclusive;
+
+    #[rstest]
+    #[case("467..114..722",[0,2,5,9,10,12],[Some((0..=2,467)),Some((0..=2,467)),Some((5..=7,114)),None,Some((10..=12,722)),Some((10..=12,722))])]
+    fn test_get_number_index(
+        #[case] input: &str,
+        #[case] indexes: [usize; 6],
+        #[case] results: [Option<(RangeInclusive<usize>, u32)>; 6],
+    ) {
+        for (indx, result) in indexes.iter().zip(results.iter()) {
+            assert_eq!(get_number_index(input.as_bytes(), *indx), *result)
+        }
+    }
+}
@@ -0,0 +1,889 @@
+use aoc_utils::{AocError, DayInput, PuzzleInput, Solution};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Display;
+
+/// Inclusive range of values, `(start, end)`
+pub type Interval = (u64, u64);
+
+#[derive(Debug)]
+struct XMapY {
+    in_min: u64,
+    in_max: u64,
+    out_min: u64,
+    /// Line of the almanac it comes from, 0 when it doesn't come from one
+    line: usize,
+}
+
+impl XMapY {
+    /// Fails on an empty range and on a range going past u64::MAX
+    fn new(in_min: u64, out_min: u64, offset: u64) -> Result<Self, String> {
+        let last = offset
+            .checked_sub(1)
+            .ok_or_else(|| "zero-length range".to_owned())?;
+        let in_max = in_min
+            .checked_add(last)
+            .ok_or_else(|| format!("source range {in_min} + {offset} overflows u64"))?;
+        out_min
+            .checked_add(last)
+            .ok_or_else(|| format!("destination range {out_min} + {offset} overflows u64"))?;
+        Ok(Self {
+            in_min,
+            in_max,
+            out_min,
+            line: 0,
+        })
+    }
+
+    fn map(&self, to_map: u64) -> Option<u64> {
+        if self.in_min > to_map || to_map > self.in_max {
+            return None;
+        }
+        Some(self.out_min + (to_map - self.in_min))
+    }
+
+    /// The part of `interval` covered by this XMapY,
+    /// the parts before and after it are pushed to `rest`
+    fn overlap(&self, (start, end): Interval, rest: &mut Vec<Interval>) -> Option<Interval> {
+        if end < self.in_min || start > self.in_max {
+            rest.push((start, end));
+            return None;
+        }
+        if start < self.in_min {
+            rest.push((start, self.in_min - 1));
+        }
+        if end > self.in_max {
+            rest.push((self.in_max + 1, end));
+        }
+        Some((start.max(self.in_min), end.min(self.in_max)))
+    }
+}
+
+/// Sort the intervals and merge the ones that overlap or touch
+fn merge(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort_unstable();
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// The numbers of a map line, `$out_min $in_min $offset`,
+/// the range they describe is left to [`XMapY::new`]
+fn parse_range(value: &str) -> Result<[u64; 3], String> {
+    let parsed = value
+        .split_whitespace()
+        .map(str::parse::<u64>)
+        .collect::<Result<Vec<u64>, _>>()
+        .map_err(|_| format!("couldn't parse map line: {value}"))?;
+    parsed
+        .try_into()
+        .map_err(|parsed: Vec<u64>| format!("expected 3 numbers, found {}", parsed.len()))
+}
+
+/// The seed ranges of part 2, the first line read as pairs of start and length
+fn seed_ranges(seeds: &[u64]) -> Result<Vec<Interval>, String> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(format!(
+            "expected pairs of seed start and length, found {} numbers",
+            seeds.len()
+        ));
+    }
+    seeds
+        .chunks_exact(2)
+        .map(|pair| {
+            pair[1]
+                .checked_sub(1)
+                .and_then(|len| pair[0].checked_add(len))
+                .map(|end| (pair[0], end))
+                .ok_or_else(|| {
+                    format!(
+                        "seed range {} + {} is empty or overflows u64",
+                        pair[0], pair[1]
+                    )
+                })
+        })
+        .collect()
+}
+
+/// A range of the almanac that makes no sense, found by [`Almanac::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeIssue {
+    pub line: usize,
+    pub message: String,
+}
+
+/// The category of the seeds listed on the first line
+const SEED: &str = "seed";
+/// The category part 1 and part 2 look for
+const LOCATION: &str = "location";
+
+#[derive(Debug, Default)]
+pub struct Mapper {
+    x_mappers: Vec<XMapY>,
+}
+
+impl Mapper {
+    fn map(&self, to_map: u64) -> u64 {
+        let out = self.x_mappers.iter().find_map(|mapper| mapper.map(to_map));
+        if let Some(out) = out {
+            out
+        } else {
+            to_map
+        }
+    }
+
+    /// Source ranges overlapping one listed before them in the same map
+    fn overlaps(&self) -> Vec<RangeIssue> {
+        let mut issues = Vec::new();
+        for (indx, x_mapper) in self.x_mappers.iter().enumerate() {
+            for other in &self.x_mappers[..indx] {
+                if x_mapper.in_min <= other.in_max && other.in_min <= x_mapper.in_max {
+                    issues.push(RangeIssue {
+                        line: x_mapper.line,
+                        message: format!(
+                            "source range {}..={} overlaps {}..={} from line {}",
+                            x_mapper.in_min,
+                            x_mapper.in_max,
+                            other.in_min,
+                            other.in_max,
+                            other.line
+                        ),
+                    });
+                }
+            }
+        }
+        issues
+    }
+
+    /// Split `interval` at the XMapY boundaries, each piece comes with the value its start maps to
+    fn split(&self, interval: Interval) -> Vec<(Interval, u64)> {
+        let mut out = Vec::new();
+        let mut pending = vec![interval];
+        // like map, the first XMapY covering a value wins
+        for x_mapper in &self.x_mappers {
+            let mut rest = Vec::new();
+            for interval in pending {
+                if let Some(piece) = x_mapper.overlap(interval, &mut rest) {
+                    out.extend(x_mapper.map(piece.0).map(|mapped| (piece, mapped)));
+                }
+            }
+            pending = rest;
+        }
+        out.extend(pending.into_iter().map(|piece| (piece, piece.0)));
+        out
+    }
+
+    /// Map every value of `intervals`, the result is sorted and merged
+    fn map_intervals(&self, intervals: &[Interval]) -> Vec<Interval> {
+        let mapped = intervals
+            .iter()
+            .flat_map(|&interval| self.split(interval))
+            .map(|((start, end), mapped)| (mapped, mapped + (end - start)))
+            .collect();
+        merge(mapped)
+    }
+}
+
+/// `start..=end` maps to `out_start..`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Segment {
+    start: u64,
+    end: u64,
+    out_start: u64,
+}
+
+impl Segment {
+    fn out_end(&self) -> u64 {
+        self.out_start + (self.end - self.start)
+    }
+}
+
+/// A chain of mappers flattened into one piecewise function: sorted, disjoint
+/// segments covering every u64, so a lookup is a binary search
+#[derive(Debug)]
+pub struct Composed {
+    from: String,
+    to: String,
+    segments: Vec<Segment>,
+}
+
+impl Composed {
+    fn new(from: &str, to: &str, mappers: &[&Mapper]) -> Self {
+        let mut segments = vec![Segment {
+            start: 0,
+            end: u64::MAX,
+            out_start: 0,
+        }];
+        for mapper in mappers {
+            let mut next = Vec::new();
+            for segment in segments {
+                for ((start, end), mapped) in mapper.split((segment.out_start, segment.out_end())) {
+                    next.push(Segment {
+                        start: segment.start + (start - segment.out_start),
+                        end: segment.start + (end - segment.out_start),
+                        out_start: mapped,
+                    });
+                }
+            }
+            next.sort_unstable_by_key(|x| x.start);
+            segments = next;
+        }
+
+        // join the neighbours that map to neighbours, mostly the ones split by an earlier stage
+        let mut joined: Vec<Segment> = Vec::with_capacity(segments.len());
+        for segment in segments {
+            match joined.last_mut() {
+                Some(last)
+                    if last.end + 1 == segment.start
+                        && last.out_end().checked_add(1) == Some(segment.out_start) =>
+                {
+                    last.end = segment.end;
+                }
+                _ => joined.push(segment),
+            }
+        }
+        Self {
+            from: from.to_owned(),
+            to: to.to_owned(),
+            segments: joined,
+        }
+    }
+
+    pub fn map(&self, value: u64) -> u64 {
+        let segment = &self.segments[self.segments.partition_point(|x| x.end < value)];
+        segment.out_start + (value - segment.start)
+    }
+
+    /// Every value mapping to `value`, sorted. Empty when nothing maps there,
+    /// several values when different segments land on it.
+    pub fn inverse(&self, value: u64) -> Vec<u64> {
+        let mut found: Vec<u64> = self
+            .segments
+            .iter()
+            .filter(|x| x.out_start <= value && value <= x.out_end())
+            .map(|x| x.start + (value - x.out_start))
+            .collect();
+        found.sort_unstable();
+        found
+    }
+
+    /// Every value mapping into `intervals`, sorted and merged
+    pub fn inverse_intervals(&self, intervals: &[Interval]) -> Vec<Interval> {
+        let mut found = Vec::new();
+        for segment in &self.segments {
+            for &(start, end) in intervals {
+                let start = start.max(segment.out_start);
+                let end = end.min(segment.out_end());
+                if start <= end {
+                    found.push((
+                        segment.start + (start - segment.out_start),
+                        segment.start + (end - segment.out_start),
+                    ));
+                }
+            }
+        }
+        merge(found)
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+}
+
+impl Display for Composed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<[String; 3]> = self
+            .segments
+            .iter()
+            .map(|x| {
+                [
+                    format!("{}..={}", x.start, x.end),
+                    format!("{}..={}", x.out_start, x.out_end()),
+                    format!("{:+}", i128::from(x.out_start) - i128::from(x.start)),
+                ]
+            })
+            .collect();
+        let header = [self.from.clone(), self.to.clone(), "offset".to_owned()];
+        let widths: Vec<usize> = (0..3)
+            .map(|col| {
+                rows.iter()
+                    .chain([&header])
+                    .map(|row| row[col].len())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        for row in [&header].into_iter().chain(&rows) {
+            writeln!(
+                f,
+                "{:>w0$} -> {:>w1$}  {:>w2$}",
+                row[0],
+                row[1],
+                row[2],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// One `<from>-to-<to> map:` section of the almanac
+#[derive(Debug)]
+struct CategoryMap {
+    from: String,
+    to: String,
+    mapper: Mapper,
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<CategoryMap>,
+    /// The seeds read as pairs of start and length, None if lenient parsing found they aren't
+    seed_ranges: Option<Vec<Interval>>,
+    /// The ranges left out of the maps: empty, past u64::MAX, or overlapping with lenient parsing
+    skipped: Vec<RangeIssue>,
+}
+
+impl Almanac {
+    /// The mappers leading from category `from` to category `to`, in the order they
+    /// apply, None if no chain of maps connects them. Picks the shortest chain.
+    fn path(&self, from: &str, to: &str) -> Option<Vec<&Mapper>> {
+        // category -> index of the map that reached it first
+        let mut reached_by: HashMap<&str, usize> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                break;
+            }
+            for (indx, map) in self.maps.iter().enumerate() {
+                if map.from == category && map.to != from && !reached_by.contains_key(&*map.to) {
+                    reached_by.insert(&map.to, indx);
+                    queue.push_back(&map.to);
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut category = to;
+        while category != from {
+            let map = &self.maps[*reached_by.get(category)?];
+            path.push(&map.mapper);
+            category = &map.from;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Follow the maps from category `from` to category `to`,
+    /// e.g. `almanac.map("seed", "location", 79)`
+    pub fn map(&self, from: &str, to: &str, value: u64) -> Option<u64> {
+        let path = self.path(from, to)?;
+        Some(path.iter().fold(value, |value, mapper| mapper.map(value)))
+    }
+
+    /// Report the ranges that make no sense: empty ones, ones going past u64::MAX, and the
+    /// overlapping ones [`Almanac::map`] would silently resolve with the first listed.
+    /// The ranges lenient parsing skipped are still reported.
+    pub fn validate(&self) -> Vec<RangeIssue> {
+        let mut issues: Vec<RangeIssue> = self
+            .maps
+            .iter()
+            .flat_map(|x| x.mapper.overlaps())
+            .chain(self.skipped.iter().cloned())
+            .collect();
+        issues.sort_unstable_by_key(|x| x.line);
+        issues
+    }
+
+    /// Flatten the maps from category `from` to category `to` into a single function
+    pub fn compose(&self, from: &str, to: &str) -> Option<Composed> {
+        Some(Composed::new(from, to, &self.path(from, to)?))
+    }
+
+    /// The values of category `from` that [`Almanac::map`] sends to `value` in category `to`
+    pub fn inverse(&self, from: &str, to: &str, value: u64) -> Option<Vec<u64>> {
+        Some(self.compose(from, to)?.inverse(value))
+    }
+
+    /// The values of category `from` that [`Almanac::map`] sends into `intervals`
+    /// of category `to`, sorted and merged
+    pub fn inverse_intervals(
+        &self,
+        from: &str,
+        to: &str,
+        intervals: &[Interval],
+    ) -> Option<Vec<Interval>> {
+        Some(self.compose(from, to)?.inverse_intervals(intervals))
+    }
+
+    /// Like [`Almanac::map`] for every value of `intervals`, the result is sorted and merged
+    pub fn map_intervals(
+        &self,
+        from: &str,
+        to: &str,
+        intervals: &[Interval],
+    ) -> Option<Vec<Interval>> {
+        let path = self.path(from, to)?;
+        Some(
+            path.iter()
+                .fold(merge(intervals.to_vec()), |intervals, mapper| {
+                    mapper.map_intervals(&intervals)
+                }),
+        )
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+    const INPUT: DayInput = aoc_utils::day_input!();
+
+    type Input = Almanac;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, AocError> {
+        let seeds: Vec<u64> = input
+            .line(0)
+            .and_then(|x| x.split_once(':'))
+            .ok_or_else(|| AocError::parse(Self::DAY, 1, "seeds line not found"))?
+            .1
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| AocError::parse(Self::DAY, 1, "couldn't parse seeds"))?;
+        if seeds.is_empty() {
+            return Err(AocError::parse(Self::DAY, 1, "no seeds"));
+        }
+        // only part 2 reads them as pairs, lenient parsing still answers part 1 without them
+        let seed_ranges = input.check(Self::DAY, 1, seed_ranges(&seeds))?;
+        let mut maps: Vec<CategoryMap> = Vec::new();
+        let mut skipped: Vec<RangeIssue> = Vec::new();
+        for (indx, line) in input.lines().enumerate().skip(1) {
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(header) = line.strip_suffix(" map:") {
+                let (from, to) = header.split_once("-to-").ok_or_else(|| {
+                    AocError::parse(
+                        Self::DAY,
+                        indx + 1,
+                        format!("expected `<from>-to-<to> map:`, found {line}"),
+                    )
+                })?;
+                if maps.iter().any(|x| x.from == from && x.to == to) {
+                    return Err(AocError::parse(
+                        Self::DAY,
+                        indx + 1,
+                        format!("second {from}-to-{to} map"),
+                    ));
+                }
+                maps.push(CategoryMap {
+                    from: from.to_owned(),
+                    to: to.to_owned(),
+                    mapper: Mapper::default(),
+                });
+            } else {
+                let map = maps.last_mut().ok_or_else(|| {
+                    AocError::parse(Self::DAY, indx + 1, "map line before the first map header")
+                })?;
+                let Some([out_min, in_min, offset]) =
+                    input.check(Self::DAY, indx + 1, parse_range(line))?
+                else {
+                    continue;
+                };
+                // the range itself goes through validate, with the overlaps
+                match XMapY::new(in_min, out_min, offset) {
+                    Ok(x) => map.mapper.x_mappers.push(XMapY {
+                        line: indx + 1,
+                        ..x
+                    }),
+                    Err(message) => skipped.push(RangeIssue {
+                        line: indx + 1,
+                        message,
+                    }),
+                }
+            }
+        }
+        let mut almanac = Almanac {
+            seeds,
+            maps,
+            seed_ranges,
+            skipped,
+        };
+        let issues = almanac.validate();
+        for issue in &issues {
+            input.check::<(), _>(Self::DAY, issue.line, Err(&issue.message))?;
+        }
+        // lenient parsing, skip the ranges overlapping an earlier one like the other bad lines
+        for map in &mut almanac.maps {
+            map.mapper
+                .x_mappers
+                .retain(|x| !issues.iter().any(|issue| issue.line == x.line));
+        }
+        almanac.skipped = issues;
+        if almanac.path(SEED, LOCATION).is_none() {
+            return Err(AocError::parse(
+                Self::DAY,
+                input.len(),
+                format!("no chain of maps leads from {SEED} to {LOCATION}"),
+            ));
+        }
+        Ok(almanac)
+    }
+
+    fn part1(almanac: &Self::Input) -> impl Display {
+        let composed = almanac
+            .compose(SEED, LOCATION)
+            .expect("parse checked that seeds lead to locations");
+        tracing::debug!(
+            "almanac composed in {} segments:\n{composed}",
+            composed.len()
+        );
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| composed.map(seed))
+            .min()
+            .unwrap()
+    }
+
+    fn part2(almanac: &Self::Input) -> impl Display {
+        let Some(seed_intervals) = &almanac.seed_ranges else {
+            return "no seed ranges, the seeds aren't pairs of start and length".to_owned();
+        };
+        // sorted, so the first interval holds the lowest location
+        almanac
+            .map_intervals(SEED, LOCATION, seed_intervals)
+            .expect("parse checked that seeds lead to locations")[0]
+            .0
+            .to_string()
+    }
+}
+
+aoc_utils::example_tests!(Day05);
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        merge, parse_range, seed_ranges, Almanac, CategoryMap, Day05, Interval, Mapper, XMapY,
+    };
+    use aoc_utils::{AocError, ParseMode, PuzzleInput, Solution};
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case((0, 4), None, &[(0, 4)])]
+    #[case((10, 12), Some((10, 12)), &[])]
+    #[case((8, 11), Some((10, 11)), &[(8, 9)])]
+    #[case((12, 20), Some((12, 14)), &[(15, 20)])]
+    #[case((5, 30), Some((10, 14)), &[(5, 9), (15, 30)])]
+    fn test_overlap(
+        #[case] interval: Interval,
+        #[case] expected: Option<Interval>,
+        #[case] expected_rest: &[Interval],
+    ) {
+        // 10..=14 -> 20..=24
+        let x_mapper = XMapY::new(10, 20, 5).unwrap();
+        let mut rest = Vec::new();
+        assert_eq!(x_mapper.overlap(interval, &mut rest), expected);
+        assert_eq!(rest, expected_rest);
+    }
+
+    #[rstest]
+    #[case("50 98 2", Ok((98, 99, 50)))]
+    #[case("0 18446744073709551615 1", Ok((u64::MAX, u64::MAX, 0)))]
+    #[case("50 98 0", Err("zero-length range"))]
+    #[case(
+        "0 18446744073709551615 2",
+        Err("source range 18446744073709551615 + 2 overflows u64")
+    )]
+    #[case(
+        "18446744073709551610 0 7",
+        Err("destination range 18446744073709551610 + 7 overflows u64")
+    )]
+    #[case("50 98", Err("expected 3 numbers, found 2"))]
+    #[case("50 -98 2", Err("couldn't parse map line: 50 -98 2"))]
+    fn test_map_line(#[case] line: &str, #[case] expected: Result<(u64, u64, u64), &str>) {
+        let parsed = parse_range(line)
+            .and_then(|[out_min, in_min, offset]| XMapY::new(in_min, out_min, offset))
+            .map(|x| (x.in_min, x.in_max, x.out_min));
+        assert_eq!(parsed, expected.map_err(str::to_owned));
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![(5, 6), (1, 2)], vec![(1, 2), (5, 6)])]
+    #[case(vec![(3, 4), (1, 2), (4, 9)], vec![(1, 9)])]
+    #[case(vec![(0, u64::MAX), (3, 4)], vec![(0, u64::MAX)])]
+    fn test_merge(#[case] intervals: Vec<Interval>, #[case] expected: Vec<Interval>) {
+        assert_eq!(merge(intervals), expected);
+    }
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    fn almanac(text: &str) -> Almanac {
+        Day05::parse(&PuzzleInput::new(text)).unwrap()
+    }
+
+    #[rstest]
+    #[case("seed", "location", 79, Some(82))]
+    #[case("seed", "soil", 14, Some(14))]
+    #[case("soil", "fertilizer", 81, Some(81))]
+    #[case("fertilizer", "water", 53, Some(49))]
+    #[case("light", "light", 7, Some(7))]
+    #[case("location", "seed", 82, None)]
+    #[case("seed", "moon", 1, None)]
+    fn test_map(
+        #[case] from: &str,
+        #[case] to: &str,
+        #[case] value: u64,
+        #[case] expected: Option<u64>,
+    ) {
+        assert_eq!(almanac(EXAMPLE).map(from, to, value), expected);
+    }
+
+    #[test]
+    fn test_map_intervals_matches_map() {
+        let almanac = almanac(EXAMPLE);
+        let intervals = [(0, 20), (45, 60), (90, 110)];
+        let brute_force: Vec<Interval> = intervals
+            .iter()
+            .flat_map(|&(start, end)| start..=end)
+            .filter_map(|x| almanac.map("seed", "location", x))
+            .map(|x| (x, x))
+            .collect();
+        assert_eq!(
+            almanac.map_intervals("seed", "location", &intervals),
+            Some(merge(brute_force))
+        );
+    }
+
+    #[test]
+    fn test_composed_matches_map() {
+        let almanac = almanac(EXAMPLE);
+        let composed = almanac.compose("seed", "location").unwrap();
+        for seed in (0..200).chain([u64::MAX - 1, u64::MAX]) {
+            assert_eq!(
+                Some(composed.map(seed)),
+                almanac.map("seed", "location", seed)
+            );
+        }
+        assert!(composed
+            .segments
+            .windows(2)
+            .all(|x| x[0].end + 1 == x[1].start));
+    }
+
+    #[test]
+    fn test_composed_display() {
+        let text =
+            "seeds: 1 2\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-location map:\n0 50 2\n";
+        let composed = almanac(text).compose("seed", "location").unwrap();
+        let table = composed.to_string();
+        let rows: Vec<String> = table
+            .lines()
+            .map(|x| x.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                "seed -> location offset",
+                "0..=49 -> 0..=49 +0",
+                "50..=97 -> 52..=99 +2",
+                "98..=99 -> 0..=1 -98",
+                "100..=18446744073709551615 -> 100..=18446744073709551615 +0",
+            ]
+        );
+        assert!(table
+            .lines()
+            .all(|x| x.len() == table.lines().next().unwrap().len()));
+    }
+
+    #[rstest]
+    #[case(82, &[79])]
+    #[case(35, &[13])]
+    #[case(5, &[30])]
+    fn test_inverse(#[case] location: u64, #[case] expected: &[u64]) {
+        let almanac = almanac(EXAMPLE);
+        assert_eq!(
+            almanac.inverse("seed", "location", location).unwrap(),
+            expected
+        );
+        for &seed in expected {
+            assert_eq!(almanac.map("seed", "location", seed), Some(location));
+        }
+    }
+
+    #[test]
+    fn test_lowest_location_by_inverse() {
+        let almanac = almanac(EXAMPLE);
+        let composed = almanac.compose("seed", "location").unwrap();
+        let seeds = [(79, 92), (55, 67)];
+        let lowest = (0..)
+            .find(|&location| {
+                composed.inverse(location).iter().any(|seed| {
+                    seeds
+                        .iter()
+                        .any(|&(start, end)| (start..=end).contains(seed))
+                })
+            })
+            .unwrap();
+        assert_eq!(lowest, 46);
+    }
+
+    /// Values above this are left alone by the random almanacs
+    const MAX_MAPPED: u64 = 200;
+
+    fn random_almanac() -> impl Strategy<Value = Almanac> {
+        let x_mapper = (0..150_u64, 0..150_u64, 1..50_u64)
+            .prop_map(|(in_min, out_min, len)| XMapY::new(in_min, out_min, len).unwrap());
+        proptest::collection::vec(proptest::collection::vec(x_mapper, 0..5), 1..5).prop_map(
+            |stages| {
+                let maps = stages
+                    .into_iter()
+                    .enumerate()
+                    .map(|(indx, x_mappers)| CategoryMap {
+                        from: format!("c{indx}"),
+                        to: format!("c{}", indx + 1),
+                        mapper: Mapper { x_mappers },
+                    })
+                    .collect();
+                Almanac {
+                    seeds: Vec::new(),
+                    maps,
+                    seed_ranges: None,
+                    skipped: Vec::new(),
+                }
+            },
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn test_inverse_matches_map(
+            almanac in random_almanac(),
+            start in 0..MAX_MAPPED,
+            len in 0..60_u64,
+        ) {
+            let last = format!("c{}", almanac.maps.len());
+            let interval = (start, start + len);
+            let inverse = almanac.inverse_intervals("c0", &last, &[interval]).unwrap();
+            for value in 0..=MAX_MAPPED {
+                let mapped = almanac.map("c0", &last, value).unwrap();
+                let in_inverse = inverse.iter().any(|&(start, end)| (start..=end).contains(&value));
+                prop_assert_eq!(in_inverse, (interval.0..=interval.1).contains(&mapped));
+            }
+            for &(start, end) in &inverse {
+                for value in [start, end] {
+                    let mapped = almanac.map("c0", &last, value).unwrap();
+                    prop_assert!((interval.0..=interval.1).contains(&mapped));
+                }
+            }
+
+            let expected: Vec<u64> = (0..=MAX_MAPPED)
+                .filter(|&value| almanac.map("c0", &last, value) == Some(start))
+                .collect();
+            prop_assert_eq!(almanac.inverse("c0", &last, start).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_maps_in_any_order() {
+        let text = "seeds: 1 5\n\nsoil-to-location map:\n0 10 5\n\nseed-to-soil map:\n10 0 5\n";
+        let almanac = almanac(text);
+        assert_eq!(almanac.map("seed", "location", 3), Some(3));
+        assert_eq!(almanac.map("seed", "soil", 3), Some(13));
+        assert_eq!(almanac.map("seed", "location", 7), Some(7));
+    }
+
+    #[test]
+    fn test_validate() {
+        let text = "seeds: 1 2\n\nseed-to-location map:\n0 10 5\n0 20 5\n0 12 2\n0 0 30\n5 40 0\n0 18446744073709551615 2\n";
+        let input = PuzzleInput::new(text).with_mode(ParseMode::Lenient);
+        let almanac = Day05::parse(&input).unwrap();
+        let lines: Vec<usize> = input.warnings().iter().map(|x| x.line).collect();
+        assert_eq!(lines, [6, 7, 7, 7, 8, 9]);
+        assert_eq!(
+            input.warnings()[0].message,
+            "source range 12..=13 overlaps 10..=14 from line 4"
+        );
+        assert_eq!(input.warnings()[4].message, "zero-length range");
+        // the skipped ranges are still reported, and left out of the maps
+        let issues: Vec<(usize, String)> = almanac
+            .validate()
+            .into_iter()
+            .map(|x| (x.line, x.message))
+            .collect();
+        let warnings: Vec<(usize, String)> = input
+            .warnings()
+            .iter()
+            .map(|x| (x.line, x.message.clone()))
+            .collect();
+        assert_eq!(issues, warnings);
+        assert_eq!(almanac.map("seed", "location", 12), Some(2));
+        assert_eq!(almanac.map("seed", "location", 5), Some(5));
+        assert_eq!(almanac.map("seed", "location", 40), Some(40));
+    }
+
+    #[rstest]
+    #[case(&[79, 14, 55, 13], Ok(vec![(79, 92), (55, 67)]))]
+    #[case(&[79, 14, 55], Err("expected pairs of seed start and length, found 3 numbers"))]
+    #[case(&[1, 0], Err("seed range 1 + 0 is empty or overflows u64"))]
+    #[case(&[u64::MAX, 1], Ok(vec![(u64::MAX, u64::MAX)]))]
+    #[case(&[u64::MAX, 2], Err("seed range 18446744073709551615 + 2 is empty or overflows u64"))]
+    fn test_seed_ranges(#[case] seeds: &[u64], #[case] expected: Result<Vec<Interval>, &str>) {
+        assert_eq!(seed_ranges(seeds), expected.map_err(str::to_owned));
+    }
+
+    #[rstest]
+    #[case("seeds: 79 14 55\n\nseed-to-location map:\n0 50 10\n", "5")]
+    #[case("seeds: 0 0\n\nseed-to-location map:\n5 0 1\n", "5")]
+    fn test_seeds_not_in_pairs(#[case] text: &str, #[case] expected: &str) {
+        let err = Day05::parse(&PuzzleInput::new(text)).err();
+        assert!(
+            matches!(err, Some(AocError::Parse { line: 1, .. })),
+            "{err:?}"
+        );
+        let input = PuzzleInput::new(text).with_mode(ParseMode::Lenient);
+        let almanac = Day05::parse(&input).unwrap();
+        assert_eq!(input.warnings().len(), 1);
+        assert_eq!(input.warnings()[0].line, 1);
+        assert_eq!(Day05::part1(&almanac).to_string(), expected);
+        assert_eq!(
+            Day05::part2(&almanac).to_string(),
+            "no seed ranges, the seeds aren't pairs of start and length"
+        );
+    }
+
+    #[rstest]
+    #[case("seeds:\n\nseed-to-location map:\n1 2 3\n", 1)]
+    #[case("seeds: 1 x\n\nseed-to-location map:\n1 2 3\n", 1)]
+    #[case("seeds: 1 2 3\n\nseed-to-location map:\n1 2 3\n", 1)]
+    #[case("seeds: 18446744073709551615 2\n\nseed-to-location map:\n1 2 3\n", 1)]
+    #[case("seeds: 1 2\n\nseed-to-location map:\n1 2 3\n1 2 0\n", 5)]
+    #[case("seeds: 1 2\n\nseed-to-location map:\n1 2 3\n5 3 1\n", 5)]
+    #[case("seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n", 4)]
+    #[case("seeds: 1 2\n1 2 3\nseed-to-location map:\n", 2)]
+    #[case("seeds: 1 2\n\nseed-location map:\n1 2 3\n", 3)]
+    #[case("seeds: 1 2\nseed-to-location map:\nseed-to-location map:\n", 3)]
+    fn test_parse_errors(#[case] text: &str, #[case] line: usize) {
+        let err = Day05::parse(&PuzzleInput::new(text));
+        assert!(
+            matches!(err, Err(AocError::Parse { line: found, .. }) if found == line),
+            "{:?}",
+            err.err()
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt::Display;
+
+use aoc_utils::{AocError, DayInput, PuzzleInput, Solution};
+
+enum Direction {
+    Forward,
+    Backward,
+    Both,
+    None,
+}
+
+pub struct Race {
+    time: usize,
+    distance: usize,
+}
+impl Race {
+    fn get_winnings(&self) -> usize {
+        let (starting_point, direction) = self.get_winning_start_speed();
+        match direction {
+            Direction::Forward => self.wins_until_lose(starting_point, Direction::Forward),
+            Direction::Backward => self.wins_until_lose(starting_point, Direction::Backward),
+            Direction::Both => {
+                self.wins_until_lose(starting_point, Direction::Backward)
+                    + self.wins_until_lose(starting_point + 1, Direction::Forward)
+            }
+            Direction::None => 1,
+        }
+    }
+
+    fn wins_until_lose(&self, starting_point: usize, direction: Direction) -> usize {
+        let offsetting = match direction {
+            Direction::Forward => |x| x + 1,
+            Direction::Backward => |x| x - 1,
+            _ => panic!("Impossible direction"),
+        };
+        let mut wins = 0;
+        let mut curr_point = starting_point;
+        while self.is_winnable(curr_point) {
+            wins += 1;
+            if curr_point == 0 || curr_point == self.time {
+                break;
+            }
+            curr_point = offsetting(curr_point);
+        }
+        wins
+    }
+    fn get_winning_start_speed(&self) -> (usize, Direction) {
+        //start by estimating half time as speed (rounded down)
+        let speed = self.time / 2;
+        let mut front_speed = speed + 1;
+        let mut back_speed = speed - 1;
+        if self.is_winnable(speed) {
+            let is_front = self.is_winnable(front_speed);
+            let is_back = self.is_winnable(back_speed);
+            let direction = match (is_back, is_front) {
+                (true, true) => Direction::Both,
+                (true, false) => Direction::Backward,
+                (false, true) => Direction::Forward,
+                (false, false) => Direction::None,
+            };
+            return (speed, direction);
+        }
+        loop {
+            if self.is_winnable(front_speed) {
+                return (front_speed, Direction::Forward);
+            } else if self.is_winnable(back_speed) {
+                return (front_speed, Direction::Backward);
+            }
+            if back_speed == 0 && front_speed == self.time {
+                panic!("This race is unwinnable")
+            }
+            back_speed = back_speed.saturating_sub(1);
+            if front_speed < self.time {
+                front_speed += 1;
+            }
+        }
+    }
+    fn is_winnable(&self, speed: usize) -> bool {
+        let remaning_time = self.time - speed;
+        speed * remaning_time > self.distance
+    }
+}
+
+fn parse_line(line: &str) -> Option<Vec<usize>> {
+    line.split_once(':')?
+        .1
+        .split_whitespace()
+        .map(str::parse::<usize>)
+        .collect::<Result<_, _>>()
+        .ok()
+}
+
+/// The races as printed on the sheet, and the single race you get by ignoring the spaces
+pub struct Races {
+    races: Vec<Race>,
+    kerned_race: Race,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+    const INPUT: DayInput = aoc_utils::day_input!();
+
+    type Input = Races;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, AocError> {
+        let times: Vec<usize> = input
+            .line(0)
+            .ok_or_else(|| AocError::parse(Self::DAY, 1, "Time line not found"))
+            .and_then(|x| {
+                parse_line(x).ok_or_else(|| AocError::parse(Self::DAY, 1, "couldn't parse times"))
+            })?;
+        let distances: Vec<usize> = input
+            .line(1)
+            .ok_or_else(|| AocError::parse(Self::DAY, 2, "Distance line not found"))
+            .and_then(|x| {
+                parse_line(x)
+                    .ok_or_else(|| AocError::parse(Self::DAY, 2, "couldn't parse distances"))
+            })?;
+        if times.len() != distances.len() {
+            return Err(AocError::parse(
+                Self::DAY,
+                2,
+                format!("{} times but {} distances", times.len(), distances.len()),
+            ));
+        }
+        let races: Vec<Race> = times
+            .iter()
+            .zip(distances.iter())
+            .map(|(time, distance)| Race {
+                time: *time,
+                distance: *distance,
+            })
+            .collect();
+        let time_part2: String = times.iter().map(|x| x.to_string()).collect();
+        let distance_part2: String = distances.iter().map(|x| x.to_string()).collect();
+        let kerned_race = Race {
+            time: time_part2
+                .parse()
+                .map_err(|_| AocError::parse(Self::DAY, 1, "couldn't parse the kerned time"))?,
+            distance: distance_part2
+                .parse()
+                .map_err(|_| AocError::parse(Self::DAY, 2, "couldn't parse the kerned distance"))?,
+        };
+        Ok(Races { races, kerned_race })
+    }
+
+    fn part1(races: &Self::Input) -> impl Display {
+        races
+            .races
+            .iter()
+            .map(|x| x.get_winnings())
+            .product::<usize>()
+    }
+
+    fn part2(races: &Self::Input) -> impl Display {
+        races.kerned_race.get_winnings()
+    }
+}
+
+aoc_utils::example_tests!(Day06);
@@ -0,0 +1,247 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use aoc_utils::{AocError, DayInput, Part, PuzzleInput, Solution};
+
+const BITS_IN_BYTE: usize = 8;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+#[repr(u8)]
+enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    J,
+    Queen,
+    King,
+    Ace,
+}
+const CARD_SIZE: usize = Card::Ace as usize + 1;
+
+impl Card {
+    /// Strength of the card, in part2 J is a joker and the weakest card
+    fn rank(&self, part: Part) -> u8 {
+        match part {
+            Part::One => *self as u8,
+            Part::Two => match self.cmp(&Card::J) {
+                Ordering::Less => *self as u8 + 1,
+                Ordering::Equal => 0,
+                Ordering::Greater => *self as u8,
+            },
+        }
+    }
+}
+
+impl TryFrom<char> for Card {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'A' => Self::Ace,
+            'K' => Self::King,
+            'Q' => Self::Queen,
+            'J' => Self::J,
+            'T' => Self::Ten,
+            '9' => Self::Nine,
+            '8' => Self::Eight,
+            '7' => Self::Seven,
+            '6' => Self::Six,
+            '5' => Self::Five,
+            '4' => Self::Four,
+            '3' => Self::Three,
+            '2' => Self::Two,
+            _ => return Err(format!(r#"unknown card "{value}""#)),
+        })
+    }
+}
+
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPairs,
+    Triplet,
+    FullHouse,
+    Quadruplet,
+    Quintuplet,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand {
+    cards: [Card; 5],
+    bid: u64,
+}
+
+impl Hand {
+    fn get_hand_type(&self, part: Part) -> HandType {
+        let mut found_indexes: [usize; CARD_SIZE] = [0; CARD_SIZE];
+        for card in self.cards {
+            found_indexes[card as usize] += 1;
+        }
+        // jokers don't count on their own, they join the biggest group
+        let jokers = match part {
+            Part::One => 0,
+            Part::Two => std::mem::take(&mut found_indexes[Card::J as usize]),
+        };
+        let mut max_found = 0;
+        let mut min_found = 0;
+        let founds = found_indexes.iter().filter(|x| **x > 0);
+        for found in founds {
+            if *found > min_found {
+                match found.cmp(&max_found) {
+                    Ordering::Greater => {
+                        min_found = max_found;
+                        max_found = *found;
+                    }
+                    Ordering::Equal | Ordering::Less => {
+                        min_found = *found;
+                    }
+                }
+            }
+        }
+        match (max_found + jokers, min_found) {
+            (5, 0) => HandType::Quintuplet,
+            (4, 1) => HandType::Quadruplet,
+            (3, 2) => HandType::FullHouse,
+            (3, 1) => HandType::Triplet,
+            (2, 2) => HandType::TwoPairs,
+            (2, 1) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    /// Hands sorted by this value are sorted by strength
+    fn get_value(&self, part: Part) -> u64 {
+        let hand_type = self.get_hand_type(part);
+        let mut total: u64 = (hand_type as u64) << (5 * BITS_IN_BYTE);
+        for (i, card) in self.cards.iter().enumerate() {
+            total += (card.rank(part) as u64) << ((5 - i - 1) * BITS_IN_BYTE)
+        }
+        total
+    }
+}
+
+impl TryFrom<&str> for Hand {
+    type Error = String;
+
+    /// hand format:
+    /// $cards $bid
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut parts = value.split_whitespace();
+        let hand = parts.next().ok_or("hand not found".to_owned())?;
+        let bid = parts
+            .next()
+            .ok_or("bid not found".to_owned())?
+            .parse()
+            .map_err(|_| "couldn't parse bid".to_owned())?;
+        if hand.chars().count() != 5 {
+            return Err(format!("expected 5 cards, found {hand}"));
+        }
+        let mut cards: [Card; 5] = [Card::Two; 5];
+        for (i, c) in hand.chars().enumerate() {
+            cards[i] = c.try_into()?;
+        }
+        Ok(Self { cards, bid })
+    }
+}
+
+fn total_winnings(hands: &[Hand], part: Part) -> u64 {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
+    hands.sort_by_key(|hand| hand.get_value(part));
+    hands
+        .iter()
+        .enumerate()
+        .map(|(index, hand)| hand.bid * (index as u64 + 1))
+        .sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+    const INPUT: DayInput = aoc_utils::day_input!();
+
+    type Input = Vec<Hand>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, AocError> {
+        input.parse_lines(Self::DAY, Hand::try_from)
+    }
+
+    fn part1(hands: &Self::Input) -> impl Display {
+        total_winnings(hands, Part::One)
+    }
+
+    fn part2(hands: &Self::Input) -> impl Display {
+        total_winnings(hands, Part::Two)
+    }
+}
+
+aoc_utils::example_tests!(Day07);
+
+#[cfg(test)]
+mod test {
+    use crate::{Hand, HandType};
+    use aoc_utils::Part;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("32T3K 765", HandType::OnePair)]
+    #[case("T55J5 684", HandType::Triplet)]
+    #[case("KK677 28", HandType::TwoPairs)]
+    #[case("KTJJT 220", HandType::TwoPairs)]
+    #[case("QQQJA 483", HandType::Triplet)]
+    fn test_get_hand_type_part1(#[case] input: &str, #[case] expected: HandType) {
+        assert_eq!(
+            Hand::try_from(input).unwrap().get_hand_type(Part::One),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("32T3K 765", 0x01010008010B)]
+    #[case("T55J5 684", 0x030803030903)]
+    #[case("KK677 28", 0x020B0B040505)]
+    #[case("KTJJT 220", 0x020B08090908)]
+    #[case("QQQJA 483", 0x030A0A0A090C)]
+    fn test_get_value_part1(#[case] input: &str, #[case] expected: u64) {
+        assert_eq!(
+            Hand::try_from(input).unwrap().get_value(Part::One),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("32T3K 765", HandType::OnePair)]
+    #[case("T55J5 684", HandType::Quadruplet)]
+    #[case("KK677 28", HandType::TwoPairs)]
+    #[case("KTJJT 220", HandType::Quadruplet)]
+    #[case("QQQJA 483", HandType::Quadruplet)]
+    #[case("JJJJJ 1", HandType::Quintuplet)]
+    fn test_get_hand_type_part2(#[case] input: &str, #[case] expected: HandType) {
+        assert_eq!(
+            Hand::try_from(input).unwrap().get_hand_type(Part::Two),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("32T3K 765", 0x01020109020B)]
+    #[case("T55J5 684", 0x050904040004)]
+    #[case("KK677 28", 0x020B0B050606)]
+    #[case("KTJJT 220", 0x050B09000009)]
+    #[case("QQQJA 483", 0x050A0A0A000C)]
+    fn test_get_value_part2(#[case] input: &str, #[case] expected: u64) {
+        assert_eq!(
+            Hand::try_from(input).unwrap().get_value(Part::Two),
+            expected
+        );
+    }
+}
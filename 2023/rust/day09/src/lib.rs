@@ -0,0 +1,96 @@
+use std::fmt::Display;
+
+use aoc_utils::{AocError, DayInput, PuzzleInput, Solution};
+
+/// Extrapolate the history backwards and forwards, returns `(previous, next)`.
+///
+/// `nums` must not be empty, parse rejects empty histories.
+fn extrapolate(mut nums: Vec<i64>) -> (i64, i64) {
+    let last = *nums.last().unwrap();
+    let first = *nums.first().unwrap();
+    let mut is_odd_depth = false;
+    let mut accumulator_last = 0;
+    let mut accumulator_first = 0;
+    while !nums.windows(2).all(|couple| couple[1] == couple[0]) {
+        for x in 0..nums.len() - 1 {
+            nums[x] = nums[x + 1] - nums[x];
+        }
+        nums.pop();
+        accumulator_last += nums.last().unwrap();
+        accumulator_first += match is_odd_depth {
+            true => -nums.first().unwrap(),
+            false => *nums.first().unwrap(),
+        };
+        is_odd_depth = !is_odd_depth;
+    }
+    (first - accumulator_first, last + accumulator_last)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+    const INPUT: DayInput = aoc_utils::day_input!();
+
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, AocError> {
+        input.parse_lines(Self::DAY, |line| {
+            let nums = line
+                .split_whitespace()
+                .map(str::parse::<i64>)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| err.to_string())?;
+            if nums.is_empty() {
+                return Err("empty history".to_owned());
+            }
+            Ok(nums)
+        })
+    }
+
+    fn part1(histories: &Self::Input) -> impl Display {
+        histories
+            .iter()
+            .map(|nums| extrapolate(nums.clone()).1)
+            .sum::<i64>()
+    }
+
+    fn part2(histories: &Self::Input) -> impl Display {
+        histories
+            .iter()
+            .map(|nums| extrapolate(nums.clone()).0)
+            .sum::<i64>()
+    }
+}
+
+aoc_utils::example_tests!(Day09);
+
+#[cfg(test)]
+mod tests {
+    use super::{extrapolate, Day09};
+    use aoc_utils::{AocError, ParseMode, PuzzleInput, Solution};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(vec![10, 13, 16, 21, 30, 45], (5, 68))]
+    #[case(vec![3, 3, 3], (3, 3))]
+    #[case(vec![7], (7, 7))]
+    fn test_extrapolate(#[case] nums: Vec<i64>, #[case] expected: (i64, i64)) {
+        assert_eq!(extrapolate(nums), expected);
+    }
+
+    #[test]
+    fn test_empty_history() {
+        let text = "1 2 3\n\n4 5 6\n";
+        let err = Day09::parse(&PuzzleInput::new(text)).err();
+        assert!(
+            matches!(err, Some(AocError::Parse { line: 2, .. })),
+            "{err:?}"
+        );
+
+        let input = PuzzleInput::new(text).with_mode(ParseMode::Lenient);
+        assert_eq!(Day09::parse(&input).unwrap().len(), 2);
+        assert_eq!(input.warnings()[0].message, "empty history");
+    }
+}
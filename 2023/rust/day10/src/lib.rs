@@ -0,0 +1,381 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use aoc_utils::{AocError, DayInput, Direction, Grid, Point, PuzzleInput, Solution};
+use derive_more::Display;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Piece {
+    point: Point,
+    _type: PieceType,
+    direction: Option<Direction>,
+    distance: isize,
+}
+
+#[derive(Display, Debug, Clone, Copy)]
+enum PieceType {
+    /// |
+    #[display(fmt = "|")]
+    Vertical,
+    /// |
+    #[display(fmt = "-")]
+    Orizzontal,
+    /// L
+    // #[display(fmt = "L")]
+    #[display(fmt = "╰")]
+    BendNE,
+    /// F
+    // #[display(fmt = "F")]
+    #[display(fmt = "╭")]
+    BendSE,
+    /// 7
+    // #[display(fmt = "7")]
+    #[display(fmt = "╮")]
+    BendSW,
+    /// J
+    // #[display(fmt = "J")]
+    #[display(fmt = "╯")]
+    BendNW,
+    /// .
+    #[display(fmt = ".")]
+    Ground,
+    /// .
+    #[display(fmt = "I")]
+    Inside,
+    /// S
+    #[display(fmt = "🐱")]
+    Animal,
+}
+
+impl TryFrom<char> for PieceType {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(PieceType::Vertical),
+            '-' => Ok(PieceType::Orizzontal),
+            'L' => Ok(PieceType::BendNE),
+            'F' => Ok(PieceType::BendSE),
+            '7' => Ok(PieceType::BendSW),
+            'J' => Ok(PieceType::BendNW),
+            '.' => Ok(PieceType::Ground),
+            'S' => Ok(PieceType::Animal),
+            _ => Err(format!("unknown tile {value:?}")),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+enum DirectionError {
+    #[error("This is a ground piece")]
+    Ground,
+    #[error("This the animal piece")]
+    Animal,
+    #[error("Impossible move to {1} with piece {0}")]
+    Impossible(PieceType, Direction),
+}
+
+impl PieceType {
+    fn get_next_direction(&self, prev_dir: &Direction) -> Result<Direction, DirectionError> {
+        match self {
+            PieceType::Vertical => match prev_dir {
+                Direction::North => Ok(Direction::North),
+                Direction::South => Ok(Direction::South),
+                dir => Err(DirectionError::Impossible(*self, *dir)),
+            },
+            PieceType::Orizzontal => match prev_dir {
+                Direction::East => Ok(Direction::East),
+                Direction::West => Ok(Direction::West),
+                dir => Err(DirectionError::Impossible(*self, *dir)),
+            },
+            PieceType::BendNE => match prev_dir {
+                Direction::South => Ok(Direction::East),
+                Direction::West => Ok(Direction::North),
+                dir => Err(DirectionError::Impossible(*self, *dir)),
+            },
+            PieceType::BendSE => match prev_dir {
+                Direction::West => Ok(Direction::South),
+                Direction::North => Ok(Direction::East),
+                dir => Err(DirectionError::Impossible(*self, *dir)),
+            },
+            PieceType::BendSW => match prev_dir {
+                Direction::East => Ok(Direction::South),
+                Direction::North => Ok(Direction::West),
+                dir => Err(DirectionError::Impossible(*self, *dir)),
+            },
+            PieceType::BendNW => match prev_dir {
+                Direction::East => Ok(Direction::North),
+                Direction::South => Ok(Direction::West),
+                dir => Err(DirectionError::Impossible(*self, *dir)),
+            },
+            PieceType::Ground | PieceType::Inside => Err(DirectionError::Ground),
+            PieceType::Animal => Err(DirectionError::Animal),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map(Grid<Piece>);
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.display_with(|piece| piece._type).fmt(f)
+    }
+}
+
+impl Map {
+    fn get_mut(&mut self, point: &Point) -> Option<&mut Piece> {
+        self.0.get_mut(point)
+    }
+
+    fn get_max_distance(&self) -> isize {
+        self.0
+            .iter()
+            .map(|(_, piece)| piece.distance)
+            .max()
+            .unwrap_or(-1)
+    }
+
+    fn reset_non_loop_pipes(&mut self) -> bool {
+        if self.get_max_distance() < 1 {
+            return false;
+        }
+        for (_, piece) in self.0.iter_mut() {
+            if piece.distance == -1 || piece.direction.is_none() {
+                piece._type = PieceType::Ground;
+            }
+        }
+        true
+    }
+
+    // raycasting algorithm
+    // walk orizzontally every row
+    // when stepping on ground, that piece is:
+    // - outside the loop, if the number of "vertical" pipes passed is even
+    // - inside the loop, if the number of "vertical" pipes passed is odd
+    // Vertical pipes are defined as:
+    // - `|`
+    // - `F--J`
+    // - `L--7`
+    // Orizzontal pipes are defined as:
+    // - `-`
+    // - `F--7`
+    // - `L--J`
+    fn get_num_pieces_inside_the_loop(&mut self) -> Option<usize> {
+        if self.get_max_distance() < 1 {
+            return None;
+        }
+        let mut inside_pieces: usize = 0;
+        let mut prev_piece_type = PieceType::Ground;
+        for line in self.0.rows_mut() {
+            let mut pipes_passed: usize = 0;
+            for piece in line.iter_mut() {
+                match piece._type {
+                    PieceType::Vertical => pipes_passed += 1,
+                    PieceType::BendNE | PieceType::BendSE => prev_piece_type = piece._type,
+                    PieceType::BendSW => {
+                        if matches!(prev_piece_type, PieceType::BendNE) {
+                            pipes_passed += 1;
+                        }
+                    }
+                    PieceType::BendNW => {
+                        if matches!(prev_piece_type, PieceType::BendSE) {
+                            pipes_passed += 1;
+                        }
+                    }
+                    PieceType::Orizzontal => {}
+                    PieceType::Ground => {
+                        if !pipes_passed.is_multiple_of(2) {
+                            inside_pieces += 1;
+                            piece._type = PieceType::Inside;
+                        }
+                    }
+                    //impossible
+                    PieceType::Animal | PieceType::Inside => {}
+                }
+            }
+        }
+        Some(inside_pieces)
+    }
+}
+
+/// The map as read from the input, with the animal position still unknown pipe
+pub struct Pipes {
+    map: Map,
+    animal: Piece,
+}
+
+impl Pipes {
+    /// Walk the loop starting from the animal, returns the map with the distance of every loop piece
+    fn trace_loop(&self) -> Map {
+        let mut matrix = self.map.clone();
+        let mut animal = self.animal;
+        // to have a loop we need 2 valid starts
+        let mut to_explore: VecDeque<Piece> = VecDeque::new();
+        for dir in Direction::ALL {
+            let Some(next_point) = animal.point + dir else {
+                continue;
+            };
+            let Some(next_piece) = matrix.get_mut(&next_point) else {
+                continue;
+            };
+            if next_piece._type.get_next_direction(&dir).is_ok() {
+                next_piece.direction = Some(dir);
+                next_piece.distance = 1;
+                to_explore.push_back(*next_piece);
+            }
+        }
+        if to_explore.len() != 2 {
+            panic!("did not found 2 starts");
+        }
+        {
+            //figure out the real piece_type of the animal
+            let start_directions: Vec<Direction> =
+                to_explore.iter().flat_map(|x| x.direction).collect();
+            match start_directions[..] {
+                [Direction::North, Direction::South] => {
+                    animal._type = PieceType::Vertical;
+                    animal.direction = Some(Direction::North);
+                }
+                [Direction::East, Direction::West] => {
+                    animal._type = PieceType::Orizzontal;
+                    animal.direction = Some(Direction::East);
+                }
+                [Direction::North, Direction::East] => {
+                    animal._type = PieceType::BendNE;
+                    animal.direction = Some(Direction::North);
+                }
+                [Direction::North, Direction::West] => {
+                    animal._type = PieceType::BendNW;
+                    animal.direction = Some(Direction::North);
+                }
+                [Direction::South, Direction::West] => {
+                    animal._type = PieceType::BendSW;
+                    animal.direction = Some(Direction::South);
+                }
+                [Direction::East, Direction::South] => {
+                    animal._type = PieceType::BendSE;
+                    animal.direction = Some(Direction::East);
+                }
+                _ => panic!("What is that!?!?"),
+            }
+            // then replace it in the matrix
+            let tmp_animal = matrix.get_mut(&animal.point).unwrap();
+            tmp_animal._type = animal._type;
+            tmp_animal.direction = animal.direction;
+            tmp_animal.distance = 0;
+        }
+
+        // BFS looking for the furthest point
+        while let Some(curr) = to_explore.pop_front() {
+            let prev_distance = curr.distance;
+            let next_dir = curr
+                ._type
+                .get_next_direction(&curr.direction.unwrap())
+                .unwrap();
+            let curr = matrix.get_mut(&(curr.point + next_dir).unwrap()).unwrap();
+            if curr.direction.is_none() && curr.distance < 0 {
+                curr.direction = Some(next_dir);
+                curr.distance = prev_distance + 1;
+                to_explore.push_back(*curr);
+            }
+        }
+        matrix
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+    const INPUT: DayInput = aoc_utils::day_input!();
+
+    type Input = Pipes;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, AocError> {
+        let mut animal = Piece {
+            point: Point::default(),
+            _type: PieceType::Ground,
+            direction: None,
+            distance: 0,
+        };
+        // the first tile rejected by the parse policy, lenient parsing turns bad tiles into ground
+        let mut bad_tile: Option<AocError> = None;
+        let matrix = Grid::parse(input.lines(), |point, c| {
+            let parsed =
+                PieceType::try_from(c).map_err(|err| format!("{err} at column {}", point.x + 1));
+            let _type = match input.check(Self::DAY, point.y + 1, parsed) {
+                Ok(Some(_type)) => _type,
+                Ok(None) => PieceType::Ground,
+                Err(err) => {
+                    bad_tile.get_or_insert(err);
+                    PieceType::Ground
+                }
+            };
+            let mut tmp = Piece {
+                point,
+                _type,
+                direction: None,
+                distance: -1,
+            };
+            if let PieceType::Animal = tmp._type {
+                tmp.distance = 0;
+                animal = tmp;
+            }
+            tmp
+        })
+        .map_err(|err| AocError::parse(Self::DAY, err.row + 1, err.to_string()))?;
+        if let Some(err) = bad_tile {
+            return Err(err);
+        }
+
+        if !matches!(animal._type, PieceType::Animal) {
+            return Err(AocError::parse(Self::DAY, 1, "animal not found"));
+        }
+        Ok(Pipes {
+            map: Map(matrix),
+            animal,
+        })
+    }
+
+    fn part1(pipes: &Self::Input) -> impl Display {
+        pipes.trace_loop().get_max_distance()
+    }
+
+    fn part2(pipes: &Self::Input) -> impl Display {
+        let mut matrix = pipes.trace_loop();
+        matrix.reset_non_loop_pipes();
+        let part2 = matrix.get_num_pieces_inside_the_loop().unwrap_or(0);
+        tracing::debug!("loop after removing the other pipes:\n{matrix}");
+        part2
+    }
+}
+
+aoc_utils::example_tests!(Day10);
+
+#[cfg(test)]
+mod tests {
+    use super::Day10;
+    use aoc_utils::{AocError, ParseMode, PuzzleInput, Solution};
+
+    const CORRUPTED: &str = "-L|F7\n7S-7|\nL|7x|\n-L-J|\nL|-JF\n";
+
+    #[test]
+    fn test_unknown_tile_strict() {
+        let err = Day10::parse(&PuzzleInput::new(CORRUPTED)).err();
+        assert!(
+            matches!(err, Some(AocError::Parse { line: 3, .. })),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn test_unknown_tile_lenient() {
+        let input = PuzzleInput::new(CORRUPTED).with_mode(ParseMode::Lenient);
+        assert!(Day10::parse(&input).is_ok());
+        assert_eq!(input.warnings().len(), 1);
+        assert_eq!(input.warnings()[0].line, 3);
+        assert_eq!(input.warnings()[0].message, "unknown tile 'x' at column 4");
+    }
+}
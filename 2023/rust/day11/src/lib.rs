@@ -0,0 +1,215 @@
+use std::fmt::Display;
+
+use aoc_utils::{AocError, CancelToken, DayInput, Grid, Part, Point, PuzzleInput, Solution};
+
+const GALAXY: char = '#';
+const SPACE: char = '.';
+
+/// How many rows (or columns) an empty row (or column) becomes
+fn expand_multiplier(part: Part) -> usize {
+    match part {
+        Part::One => 2,
+        Part::Two => 1_000_000,
+    }
+}
+
+pub struct Image {
+    galaxies: Vec<Point>,
+    expanded_columns: Vec<usize>,
+    expanded_rows: Vec<usize>,
+}
+
+impl Image {
+    /// Sum of the distances between every pair of galaxies
+    ///
+    /// Reports the pairs done to the [`CancelToken`] of the part, and gives up with
+    /// a partial sum once it is cancelled.
+    fn total_distance(&self, expand_multiplier: usize) -> u64 {
+        // the empty row is already counted once by the distance
+        let calculation_multiplier = expand_multiplier - 1;
+        let galaxies = &self.galaxies;
+        let token = CancelToken::current();
+        let pairs = galaxies.len() * galaxies.len().saturating_sub(1) / 2;
+        token.set_total(pairs as u64);
+        let mut total_expansion: u64 = 0;
+        for (i, &start_galaxy) in galaxies.iter().enumerate() {
+            if token.is_cancelled() {
+                break;
+            }
+            for dest_galaxy in &galaxies[i + 1..] {
+                let height_expand: usize = self
+                    .expanded_columns
+                    .iter()
+                    .filter(|&&x| {
+                        if x > start_galaxy.x {
+                            x < dest_galaxy.x
+                        } else {
+                            x > dest_galaxy.x
+                        }
+                    })
+                    .fold(0, |i, _| i + 1);
+                let width_expand = self
+                    .expanded_rows
+                    .iter()
+                    .filter(|&&y| {
+                        if y > start_galaxy.y {
+                            y < dest_galaxy.y
+                        } else {
+                            y > dest_galaxy.y
+                        }
+                    })
+                    .fold(0, |i, _| i + 1);
+                total_expansion += (start_galaxy.manhattan(dest_galaxy)
+                    + (width_expand + height_expand) * calculation_multiplier)
+                    as u64;
+            }
+            token.advance((galaxies.len() - i - 1) as u64);
+        }
+        total_expansion
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+    const INPUT: DayInput = aoc_utils::day_input!();
+
+    type Input = Image;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Input, AocError> {
+        if input.is_empty() {
+            return Err(AocError::parse(Self::DAY, 1, "the image is empty"));
+        }
+        // the first tile rejected by the parse policy, lenient parsing turns bad tiles into space
+        let mut bad_tile: Option<AocError> = None;
+        let image = Grid::parse(input.lines(), |point, c| {
+            let parsed = match c {
+                GALAXY => Ok(true),
+                SPACE => Ok(false),
+                _ => Err(format!("unknown tile {c:?} at column {}", point.x + 1)),
+            };
+            match input.check(Self::DAY, point.y + 1, parsed) {
+                Ok(is_galaxy) => is_galaxy.unwrap_or(false),
+                Err(err) => {
+                    bad_tile.get_or_insert(err);
+                    false
+                }
+            }
+        })
+        .map_err(|err| AocError::parse(Self::DAY, err.row + 1, err.to_string()))?;
+        if let Some(err) = bad_tile {
+            return Err(err);
+        }
+        let galaxies: Vec<Point> = image.positions(|&is_galaxy| is_galaxy).collect();
+        let expanded_columns: Vec<usize> = image
+            .columns()
+            .enumerate()
+            .filter_map(|(h, mut column)| (!column.any(|&is_galaxy| is_galaxy)).then_some(h))
+            .collect();
+        let expanded_rows: Vec<usize> = image
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| !row.contains(&true))
+            .map(|(w, _)| w)
+            .collect();
+        Ok(Image {
+            galaxies,
+            expanded_columns,
+            expanded_rows,
+        })
+    }
+
+    fn part1(image: &Self::Input) -> impl Display {
+        image.total_distance(expand_multiplier(Part::One))
+    }
+
+    fn part2(image: &Self::Input) -> impl Display {
+        image.total_distance(expand_multiplier(Part::Two))
+    }
+}
+
+aoc_utils::example_tests!(Day11);
+
+#[cfg(test)]
+mod tests {
+    use super::Day11;
+    use aoc_utils::{AocError, ParseMode, Part, PuzzleInput, Registry, Solution};
+    use rstest::rstest;
+    use std::time::Duration;
+
+    const IMAGE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[rstest]
+    #[case(2, 374)]
+    #[case(10, 1030)]
+    #[case(100, 8410)]
+    fn test_total_distance(#[case] expand_multiplier: usize, #[case] expected: u64) {
+        let input = PuzzleInput::new(IMAGE);
+        let image = Day11::parse(&input).unwrap();
+        assert_eq!(image.total_distance(expand_multiplier), expected);
+    }
+
+    #[rstest]
+    #[case("...\n...\n")]
+    #[case("...\n.#.\n")]
+    fn test_fewer_than_two_galaxies(#[case] text: &str) {
+        let image = Day11::parse(&PuzzleInput::new(text)).unwrap();
+        assert_eq!(image.total_distance(2), 0);
+    }
+
+    #[test]
+    fn test_unknown_tile_strict() {
+        let err = Day11::parse(&PuzzleInput::new("#..\n.x#\n")).err();
+        assert!(
+            matches!(err, Some(AocError::Parse { line: 2, .. })),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn test_unknown_tile_lenient() {
+        let input = PuzzleInput::new("#..\n.x#\n").with_mode(ParseMode::Lenient);
+        let image = Day11::parse(&input).unwrap();
+        assert_eq!(input.warnings().len(), 1);
+        assert_eq!(input.warnings()[0].message, "unknown tile 'x' at column 2");
+        assert_eq!(image.total_distance(2), 4);
+    }
+
+    #[test]
+    fn test_timeout_reports_progress() {
+        // 10000 galaxies, every other row and column empty: 50 million pairs
+        let row: String = "#.".repeat(100);
+        let empty = ".".repeat(row.len());
+        let text = format!("{row}\n{empty}\n").repeat(100);
+        let mut registry = Registry::new();
+        registry.register::<Day11>();
+        let run = registry
+            .get(Day11::YEAR, Day11::DAY)
+            .unwrap()
+            .solve_within(
+                &PuzzleInput::new(text),
+                &[Part::One],
+                Some(Duration::from_millis(200)),
+            )
+            .unwrap();
+        let Err(AocError::Timeout { progress, .. }) = &run.answers[0].value else {
+            panic!("expected a timeout, got {:?}", run.answers[0]);
+        };
+        assert_eq!(progress.total, 10_000 * 9_999 / 2);
+        assert!(
+            progress.done > 0 && progress.done < progress.total,
+            "{progress}"
+        );
+    }
+}